@@ -0,0 +1,333 @@
+//! This module implements the `enumoid_from_file!` function-like macro, which
+//! declares an enum with a unit variant for each name listed in a data file
+//! and a `#[derive(Enumoid)]` attribute.
+//!
+//! ```ignore
+//! enumoid_from_file!(pub Sensor, "data/sensors.txt");
+//! ```
+//!
+//! with `data/sensors.txt` containing `Intake` and `Exhaust` on separate lines
+//! expands to:
+//!
+//! ```ignore
+//! #[derive(Enumoid)]
+//! pub enum Sensor {
+//!   Intake,
+//!   Exhaust,
+//! }
+//!
+//! impl Sensor {
+//!   pub const NAMES: [&'static str; 2] = ["Intake", "Exhaust"];
+//!   pub const fn name(self) -> &'static str { ... }
+//!   pub fn from_name(name: &str) -> Option<Self> { ... }
+//! }
+//! ```
+//!
+//! The path is relative to `CARGO_MANIFEST_DIR`. Files ending in `.json` hold
+//! an array of strings. Files ending in `.csv` have a header row followed by
+//! a row for each variant, whose name is in the first column, with quoting as
+//! in RFC 4180. Other files list a name on each line, ignoring blank lines and
+//! lines starting with `#`. Each name must be exactly an identifier, without
+//! surrounding whitespace or an `r#` prefix.
+//! Optional leading outer attributes and a visibility may precede the name as
+//! with `generate_enumoid!`.
+
+use std::collections::HashSet;
+use std::iter::Peekable;
+use std::path::Path;
+use std::str::Chars;
+use syn::Result;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+
+/// Parsed form of an `enumoid_from_file!` invocation:
+/// `[attrs] [vis] Name, "path"`.
+struct FromFileInput {
+  attrs: Vec<syn::Attribute>,
+  vis: syn::Visibility,
+  name: syn::Ident,
+  path: syn::LitStr,
+}
+
+impl Parse for FromFileInput {
+  fn parse(input: ParseStream) -> syn::Result<Self> {
+    let attrs = input.call(syn::Attribute::parse_outer)?;
+    let vis = input.parse()?;
+    let name = input.parse()?;
+    input.parse::<syn::Token![,]>()?;
+    let path = input.parse()?;
+    input.parse::<Option<syn::Token![,]>>()?;
+    Ok(FromFileInput {
+      attrs,
+      vis,
+      name,
+      path,
+    })
+  }
+}
+
+/// A name read from a data file, and where it was found for error messages.
+type Entry = (String, String);
+
+/// Returns the names listed in a JSON array of strings.
+fn parse_json(text: &str) -> std::result::Result<Vec<Entry>, String> {
+  let mut chars = text.chars().peekable();
+  let skip_whitespace = |chars: &mut Peekable<Chars>| {
+    while chars
+      .next_if(|c| matches!(c, ' ' | '\t' | '\n' | '\r'))
+      .is_some()
+    {}
+  };
+  let expected = || "Expected a JSON array of strings.".to_string();
+  let mut entries = Vec::new();
+  skip_whitespace(&mut chars);
+  if chars.next() != Some('[') {
+    return Err(expected());
+  }
+  skip_whitespace(&mut chars);
+  if chars.next_if_eq(&']').is_none() {
+    loop {
+      skip_whitespace(&mut chars);
+      let location = format!("element {}", entries.len() + 1);
+      if chars.next() != Some('"') {
+        return Err(expected());
+      }
+      let name = parse_json_string(&mut chars)
+        .map_err(|message| format!("{message} in {location}."))?;
+      entries.push((location, name));
+      skip_whitespace(&mut chars);
+      match chars.next() {
+        Some(',') => {}
+        Some(']') => break,
+        _ => return Err(expected()),
+      }
+    }
+  }
+  skip_whitespace(&mut chars);
+  match chars.next() {
+    None => Ok(entries),
+    Some(_) => Err(expected()),
+  }
+}
+
+/// Decodes the rest of a JSON string whose opening quote has been consumed.
+fn parse_json_string(
+  chars: &mut Peekable<Chars>,
+) -> std::result::Result<String, String> {
+  let mut value = String::new();
+  loop {
+    match chars.next().ok_or("Unterminated string")? {
+      '"' => return Ok(value),
+      '\\' => {
+        let c = match chars.next().ok_or("Unterminated string")? {
+          '"' => '"',
+          '\\' => '\\',
+          '/' => '/',
+          'b' => '\u{8}',
+          'f' => '\u{c}',
+          'n' => '\n',
+          'r' => '\r',
+          't' => '\t',
+          'u' => {
+            let high = parse_json_hex(chars)?;
+            let code = if (0xD800..0xDC00).contains(&high) {
+              // A high surrogate must be followed by an escaped low one.
+              if chars.next() != Some('\\') || chars.next() != Some('u') {
+                return Err("Unpaired surrogate".to_string());
+              }
+              let low = parse_json_hex(chars)?;
+              if !(0xDC00..0xE000).contains(&low) {
+                return Err("Unpaired surrogate".to_string());
+              }
+              0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+            } else {
+              high
+            };
+            char::from_u32(code).ok_or("Unpaired surrogate")?
+          }
+          c => return Err(format!("Invalid escape `\\{c}`")),
+        };
+        value.push(c);
+      }
+      c if c < ' ' => {
+        return Err("Unescaped control character".to_string());
+      }
+      c => value.push(c),
+    }
+  }
+}
+
+/// Parses the four hexadecimal digits of a `\u` escape.
+fn parse_json_hex(
+  chars: &mut Peekable<Chars>,
+) -> std::result::Result<u32, String> {
+  let digits = chars.take(4).collect::<String>();
+  match u32::from_str_radix(&digits, 16) {
+    Ok(code) if digits.len() == 4 && digits.is_ascii() => Ok(code),
+    _ => Err(format!("Invalid escape `\\u{digits}`")),
+  }
+}
+
+/// Returns the names in the first column of a CSV file, following RFC 4180.
+/// Fields may be quoted, in which case they may contain commas, line breaks
+/// and quotes escaped as `""`. Unquoted fields are trimmed, and blank records
+/// are ignored.
+fn parse_csv(text: &str) -> std::result::Result<Vec<Entry>, String> {
+  let mut chars = text.chars().peekable();
+  let mut line = 1;
+  let mut entries = Vec::new();
+  let mut header = true;
+  while chars.peek().is_some() {
+    let record_line = line;
+    let (quoted, name) = parse_csv_field(&mut chars, &mut line)?;
+    // Skip the remaining fields of the record.
+    loop {
+      match chars.next() {
+        Some(',') => {
+          parse_csv_field(&mut chars, &mut line)?;
+        }
+        Some('\r') if chars.next_if_eq(&'\n').is_some() => break,
+        Some('\n') | None => break,
+        Some(c) => {
+          return Err(format!(
+            "Expected `,` or a line break instead of {c:?} after a field on \
+             line {line}."
+          ));
+        }
+      }
+    }
+    line += 1;
+    if !std::mem::take(&mut header) && (quoted || !name.is_empty()) {
+      entries.push((format!("line {record_line}"), name));
+    }
+  }
+  Ok(entries)
+}
+
+/// Parses a CSV field up to the following separator, returning whether it was
+/// quoted and its value.
+fn parse_csv_field(
+  chars: &mut Peekable<Chars>,
+  line: &mut usize,
+) -> std::result::Result<(bool, String), String> {
+  let mut value = String::new();
+  if chars.next_if_eq(&'"').is_none() {
+    while let Some(c) = chars.next_if(|c| !matches!(c, ',' | '\r' | '\n')) {
+      value.push(c);
+    }
+    return Ok((false, value.trim().to_string()));
+  }
+  let start = *line;
+  loop {
+    match chars.next() {
+      Some('"') if chars.next_if_eq(&'"').is_none() => {
+        return Ok((true, value));
+      }
+      Some(c) => {
+        if c == '\n' {
+          *line += 1;
+        }
+        value.push(c);
+      }
+      None => {
+        return Err(format!(
+          "Unterminated quoted field starting on line {start}."
+        ));
+      }
+    }
+  }
+}
+
+/// Returns the names listed on each line of a text file.
+fn parse_lines(text: &str) -> Vec<Entry> {
+  text
+    .lines()
+    .enumerate()
+    .map(|(index, line)| (index, line.trim()))
+    .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+    .map(|(index, name)| (format!("line {}", index + 1), name.to_string()))
+    .collect()
+}
+
+pub fn try_enumoid_from_file(
+  input: proc_macro::TokenStream,
+) -> Result<proc_macro2::TokenStream> {
+  let FromFileInput {
+    attrs,
+    vis,
+    name,
+    path,
+  } = syn::parse(input)?;
+  let error = |message: String| syn::Error::new(path.span(), message);
+  let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+    .map_err(|_| error("CARGO_MANIFEST_DIR is not set.".to_string()))?;
+  let full_path = Path::new(&manifest_dir).join(path.value());
+  let text = std::fs::read_to_string(&full_path)
+    .map_err(|err| error(format!("Failed to read {}: {err}", path.value())))?;
+  let entries = match full_path.extension().and_then(|ext| ext.to_str()) {
+    Some("json") => parse_json(&text).map_err(error)?,
+    Some("csv") => parse_csv(&text).map_err(error)?,
+    _ => parse_lines(&text),
+  };
+  if entries.is_empty() {
+    return Err(error(format!("{} lists no variants.", path.value())));
+  }
+  let mut seen = HashSet::new();
+  let mut variants = Vec::new();
+  for (location, entry) in &entries {
+    // The name must be the variant's identifier verbatim, since `NAMES` and
+    // `from_name` use it as is.
+    let variant = syn::parse_str::<syn::Ident>(entry)
+      .ok()
+      .filter(|variant| variant.unraw() == entry);
+    let Some(variant) = variant else {
+      return Err(error(format!(
+        "`{entry}` at {location} is not a valid identifier."
+      )));
+    };
+    if !seen.insert(entry) {
+      return Err(error(format!(
+        "`{entry}` at {location} occurs more than once."
+      )));
+    }
+    variants.push(variant);
+  }
+  let names = entries.iter().map(|(_, entry)| entry).collect::<Vec<_>>();
+  let count = names.len();
+  // Including the file makes Cargo rebuild when it changes.
+  let full_path = full_path.to_string_lossy();
+  // `#[derive(Enumoid)]` must precede the caller's attributes so that its
+  // helper attributes (e.g. `#[index_type]`) are introduced before their use.
+  Ok(quote! {
+    #[derive(Enumoid)]
+    #(#attrs)*
+    #vis enum #name {
+      #(#variants),*
+    }
+    impl #name {
+      /// The name of each variant, in order.
+      #vis const NAMES: [&'static str; #count] = [#(#names),*];
+
+      /// Returns the name of this variant.
+      #[inline]
+      #vis const fn name(self) -> &'static str {
+        match self {
+          #(
+            Self::#variants => #names,
+          )*
+        }
+      }
+
+      /// Returns the variant with the given name, if any.
+      #vis fn from_name(name: &str) -> ::core::option::Option<Self> {
+        match name {
+          #(
+            #names => ::core::option::Option::Some(Self::#variants),
+          )*
+          _ => ::core::option::Option::None,
+        }
+      }
+    }
+    const _: &[u8] = include_bytes!(#full_path);
+  })
+}
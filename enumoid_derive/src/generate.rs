@@ -1,6 +1,6 @@
 //! This module implements the `generate_enumoid!` function-like macro, which
-//! declares an enum with a contiguous range of numbered unit variants and a
-//! `#[derive(Enumoid)]` attribute.
+//! declares an enum with numbered unit variants and a `#[derive(Enumoid)]`
+//! attribute.
 //!
 //! ```ignore
 //! generate_enumoid!(Foo, Bar, 1..=3);
@@ -20,19 +20,37 @@
 //! Exclusive ranges (`1..4`) are also supported. Optional leading outer
 //! attributes and a visibility may precede the name, e.g.
 //! `generate_enumoid!(#[index_type(u16)] pub Foo, Bar, 1..=300);`.
+//!
+//! The range may be followed by more comma-separated ranges and numbers, and
+//! by options:
+//!
+//! ```ignore
+//! generate_enumoid!(Foo, Bar, 0..=20 step 10, 99, pad = 3, suffix = Hz, number);
+//! ```
+//!
+//! declares the variants `Bar000Hz`, `Bar010Hz`, `Bar020Hz` and `Bar099Hz`,
+//! and an inherent `fn number(self) -> u64` returning the number a variant was
+//! generated from. The `repr` option adds the narrowest sufficient integer
+//! `repr`, which lets the derive convert with casts.
 
-use anyhow::{Result, anyhow};
+use std::collections::HashSet;
+use syn::Result;
 use syn::parse::{Parse, ParseStream};
 
 /// Parsed form of a `generate_enumoid!` invocation:
-/// `[attrs] [vis] Name, VariantPrefix, start..end`.
+/// `[attrs] [vis] Name, VariantPrefix, item, ...` where each item is a range,
+/// a number or an option.
 struct GenerateInput {
   attrs: Vec<syn::Attribute>,
   vis: syn::Visibility,
   name: syn::Ident,
   prefix: syn::Ident,
-  start: u64,
-  last: u64,
+  /// Each number with the span of the range or number producing it.
+  numbers: Vec<(u64, proc_macro2::Span)>,
+  pad: usize,
+  suffix: Option<syn::Ident>,
+  number: bool,
+  repr: bool,
 }
 
 /// Parses a single non-negative index bound of the range.
@@ -43,6 +61,59 @@ fn parse_index(input: ParseStream) -> syn::Result<u64> {
   input.parse::<syn::LitInt>()?.base10_parse::<u64>()
 }
 
+/// Parses a range with an optional step, `start..end step n`, or a single
+/// number, appending the numbers it produces.
+fn parse_numbers(
+  input: ParseStream,
+  numbers: &mut Vec<(u64, proc_macro2::Span)>,
+) -> Result<()> {
+  let range_span = input.span();
+  let start = parse_index(input)?;
+  // Check `..=` before `..` since the latter is a prefix of the former.
+  let inclusive = if input.peek(syn::Token![..=]) {
+    input.parse::<syn::Token![..=]>()?;
+    true
+  } else if input.peek(syn::Token![..]) {
+    input.parse::<syn::Token![..]>()?;
+    false
+  } else {
+    numbers.push((start, range_span));
+    return Ok(());
+  };
+  let end = parse_index(input)?;
+  let step = if input.peek(syn::Ident) {
+    let keyword = input.parse::<syn::Ident>()?;
+    if keyword != "step" {
+      return Err(syn::Error::new(keyword.span(), "Expected `step`."));
+    }
+    let step_span = input.span();
+    let step = parse_index(input)?;
+    if step == 0 {
+      return Err(syn::Error::new(step_span, "The step must be positive."));
+    }
+    step
+  } else {
+    1
+  };
+  let last = if inclusive {
+    end
+  } else {
+    end.checked_sub(1).ok_or_else(|| {
+      syn::Error::new(range_span, "The range produces no variants.")
+    })?
+  };
+  if start > last {
+    return Err(syn::Error::new(
+      range_span,
+      "The range produces no variants.",
+    ));
+  }
+  let step = usize::try_from(step)
+    .map_err(|_| syn::Error::new(range_span, "The step is too large."))?;
+  numbers.extend((start..=last).step_by(step).map(|n| (n, range_span)));
+  Ok(())
+}
+
 impl Parse for GenerateInput {
   fn parse(input: ParseStream) -> syn::Result<Self> {
     let attrs = input.call(syn::Attribute::parse_outer)?;
@@ -51,37 +122,87 @@ impl Parse for GenerateInput {
     input.parse::<syn::Token![,]>()?;
     let prefix = input.parse()?;
     input.parse::<syn::Token![,]>()?;
-    let start = parse_index(input)?;
-    // Check `..=` before `..` since the latter is a prefix of the former.
-    let inclusive = if input.peek(syn::Token![..=]) {
-      input.parse::<syn::Token![..=]>()?;
-      true
-    } else {
-      input.parse::<syn::Token![..]>()?;
-      false
-    };
-    let end = parse_index(input)?;
-    let last = if inclusive {
-      end
-    } else {
-      end
-        .checked_sub(1)
-        .ok_or_else(|| input.error("The range produces no variants."))?
-    };
-    if start > last {
-      return Err(input.error("The range produces no variants."));
+    let mut numbers = Vec::new();
+    let mut pad = None;
+    let mut suffix = None;
+    let mut number = false;
+    let mut repr = false;
+    parse_numbers(input, &mut numbers)?;
+    while !input.is_empty() {
+      input.parse::<syn::Token![,]>()?;
+      if input.is_empty() {
+        break;
+      }
+      if !input.peek(syn::Ident) {
+        parse_numbers(input, &mut numbers)?;
+        continue;
+      }
+      let option = input.parse::<syn::Ident>()?;
+      let duplicate =
+        || syn::Error::new(option.span(), "Option is given more than once.");
+      if option == "pad" {
+        input.parse::<syn::Token![=]>()?;
+        let width = input.parse::<syn::LitInt>()?.base10_parse()?;
+        if pad.replace(width).is_some() {
+          return Err(duplicate());
+        }
+      } else if option == "suffix" {
+        input.parse::<syn::Token![=]>()?;
+        if suffix.replace(input.parse()?).is_some() {
+          return Err(duplicate());
+        }
+      } else if option == "number" {
+        if number {
+          return Err(duplicate());
+        }
+        number = true;
+      } else if option == "repr" {
+        if repr {
+          return Err(duplicate());
+        }
+        if let Some(attr) = attrs.iter().find(|a| a.path().is_ident("repr")) {
+          return Err(syn::Error::new_spanned(
+            attr,
+            "The `repr` option conflicts with this attribute.",
+          ));
+        }
+        repr = true;
+      } else {
+        return Err(syn::Error::new(
+          option.span(),
+          "Unknown option to generate_enumoid.",
+        ));
+      }
     }
     Ok(GenerateInput {
       attrs,
       vis,
       name,
       prefix,
-      start,
-      last,
+      numbers,
+      pad: pad.unwrap_or(0),
+      suffix,
+      number,
+      repr,
     })
   }
 }
 
+/// Returns the narrowest `repr` attribute for an enum with `count` unit
+/// variants.
+fn narrowest_repr(count: usize) -> proc_macro2::TokenStream {
+  let count = count as u64;
+  if count <= 1 << 8 {
+    quote! { #[repr(u8)] }
+  } else if count <= 1 << 16 {
+    quote! { #[repr(u16)] }
+  } else if count <= 1 << 32 {
+    quote! { #[repr(u32)] }
+  } else {
+    quote! { #[repr(u64)] }
+  }
+}
+
 pub fn try_generate_enumoid(
   input: proc_macro::TokenStream,
 ) -> Result<proc_macro2::TokenStream> {
@@ -90,19 +211,55 @@ pub fn try_generate_enumoid(
     vis,
     name,
     prefix,
-    start,
-    last,
-  } = syn::parse(input).map_err(|e| anyhow!("{}", e))?;
-  let variants = (start..=last)
-    .map(|i| format_ident!("{}{}", prefix, i))
+    numbers,
+    pad,
+    suffix,
+    number,
+    repr,
+  } = syn::parse(input)?;
+  let mut seen = HashSet::new();
+  if let Some((n, span)) = numbers.iter().find(|(n, _)| !seen.insert(*n)) {
+    return Err(syn::Error::new(
+      *span,
+      format!("The number {n} produces more than one variant."),
+    ));
+  }
+  let numbers = numbers.into_iter().map(|(n, _)| n).collect::<Vec<_>>();
+  // Every variant shares the suffix by design.
+  let allow_suffix = suffix
+    .is_some()
+    .then(|| quote! { #[allow(clippy::enum_variant_names)] });
+  let suffix = suffix.map(|s| s.to_string()).unwrap_or_default();
+  let variants = numbers
+    .iter()
+    .map(|i| format_ident!("{}{:0pad$}{}", prefix, i, suffix))
     .collect::<Vec<_>>();
+  let repr = repr.then(|| narrowest_repr(numbers.len()));
+  let number_impl = number.then(|| {
+    quote! {
+      impl #name {
+        /// Returns the number this variant was generated from.
+        #[inline]
+        #vis const fn number(self) -> u64 {
+          match self {
+            #(
+              Self::#variants => #numbers,
+            )*
+          }
+        }
+      }
+    }
+  });
   // `#[derive(Enumoid)]` must precede the caller's attributes so that its
   // helper attributes (e.g. `#[index_type]`) are introduced before their use.
   Ok(quote! {
     #[derive(Enumoid)]
     #(#attrs)*
+    #repr
+    #allow_suffix
     #vis enum #name {
       #(#variants),*
     }
+    #number_impl
   })
 }
@@ -9,8 +9,8 @@
 //! # fn main() {}
 //! ```
 
-use anyhow::{Result, anyhow, bail};
-use quote::ToTokens;
+use syn::Result;
+use syn::spanned::Spanned;
 
 extern crate proc_macro;
 extern crate proc_macro2;
@@ -18,39 +18,61 @@ extern crate proc_macro2;
 extern crate quote;
 extern crate syn;
 
+mod from_file;
 mod generate;
 
+/// Accumulates errors so that they can all be reported at once.
+#[derive(Default)]
+struct Errors(Option<syn::Error>);
+
+impl Errors {
+  fn push(&mut self, error: syn::Error) {
+    match &mut self.0 {
+      Some(errors) => errors.combine(error),
+      None => self.0 = Some(error),
+    }
+  }
+
+  fn check<T>(&mut self, result: Result<T>) -> Option<T> {
+    result.map_err(|error| self.push(error)).ok()
+  }
+
+  fn finish<T>(mut self, result: Result<T>) -> Result<T> {
+    let value = self.check(result);
+    match self.0 {
+      Some(errors) => Err(errors),
+      None => Ok(value.expect("value is present without errors")),
+    }
+  }
+}
+
+/// Returns the index type, or `None` if it should be selected automatically.
 fn get_index_type(
   input: &syn::DeriveInput,
-) -> Result<proc_macro2::TokenStream> {
+) -> Result<Option<proc_macro2::TokenStream>> {
   for attr in input.attrs.iter() {
     if attr.path().is_ident("index_type") {
-      return match attr.parse_args() {
-        Ok(syn::TypePath { path, .. }) if path.is_ident("u8") => {
-          Ok(quote! { u8 })
-        }
-        Ok(syn::TypePath { path, .. }) if path.is_ident("u16") => {
-          Ok(quote! { u16 })
-        }
-        Ok(syn::TypePath { path, .. }) if path.is_ident("u32") => {
-          Ok(quote! { u32 })
-        }
-        Ok(syn::TypePath { path, .. }) if path.is_ident("usize") => {
-          Ok(quote! { usize })
-        }
-        Ok(e) => {
-          bail!(
-            "Invalid argument to index_type attribute: {}",
-            e.into_token_stream()
-          );
-        }
-        Err(e) => {
-          bail!("Error parsing arguments to index_type attribute: {}", e);
-        }
+      let syn::TypePath { path, .. } = attr.parse_args()?;
+      return if path.is_ident("u8") {
+        Ok(Some(quote! { u8 }))
+      } else if path.is_ident("u16") {
+        Ok(Some(quote! { u16 }))
+      } else if path.is_ident("u32") {
+        Ok(Some(quote! { u32 }))
+      } else if path.is_ident("usize") {
+        Ok(Some(quote! { usize }))
+      } else if path.is_ident("auto") {
+        Ok(None)
+      } else {
+        Err(syn::Error::new_spanned(
+          path,
+          "Invalid argument to index_type attribute, expected one of u8, u16, \
+           u32, usize, or auto.",
+        ))
       };
     }
   }
-  Ok(quote! { u8 })
+  Ok(Some(quote! { u8 }))
 }
 
 fn get_bitset_word_types(
@@ -62,58 +84,457 @@ fn get_bitset_word_types(
       let ty_list = attr.parse_args_with(syn::punctuated::Punctuated::<syn::TypePath, syn::Token![,]>::parse_terminated)?;
       tys.clear();
       for ty in ty_list {
-        tys.push(ty.into_token_stream())
+        tys.push(quote! { #ty })
       }
     }
   }
   Ok(tys)
 }
+
+/// Parses the arguments of the `enumoid` attributes in a list. The callback
+/// returns false for arguments which it does not recognise.
+fn parse_enumoid_attrs(
+  attrs: &[syn::Attribute],
+  mut f: impl FnMut(&syn::meta::ParseNestedMeta) -> Result<bool>,
+) -> Result<()> {
+  for attr in attrs.iter() {
+    if attr.path().is_ident("enumoid") {
+      attr.parse_nested_meta(|meta| {
+        if f(&meta)? {
+          Ok(())
+        } else {
+          Err(meta.error("Unknown argument to enumoid attribute."))
+        }
+      })?;
+    }
+  }
+  Ok(())
+}
+
+/// Options given by `enumoid` attributes on the type.
+struct TypeOptions {
+  /// The path given by `#[enumoid(crate = path)]`.
+  krate: syn::Path,
+  /// The `#[enumoid(discriminant)]` argument, if given.
+  discriminant: Option<syn::Path>,
+  /// Whether `#[enumoid(partial)]` allows skipped variants.
+  partial: bool,
+}
+
+impl Default for TypeOptions {
+  fn default() -> Self {
+    TypeOptions {
+      krate: syn::parse_quote! { ::enumoid },
+      discriminant: None,
+      partial: false,
+    }
+  }
+}
+
+fn get_type_options(input: &syn::DeriveInput) -> Result<TypeOptions> {
+  let mut options = TypeOptions::default();
+  parse_enumoid_attrs(&input.attrs, |meta| {
+    if meta.path.is_ident("crate") {
+      options.krate = meta.value()?.parse()?;
+      Ok(true)
+    } else if meta.path.is_ident("discriminant") {
+      options.discriminant = Some(meta.path.clone());
+      Ok(true)
+    } else if meta.path.is_ident("partial") {
+      options.partial = true;
+      Ok(true)
+    } else {
+      Ok(false)
+    }
+  })?;
+  Ok(options)
+}
+
+/// Options given by `enumoid` attributes on a variant.
+#[derive(Default)]
+struct VariantOptions {
+  /// The position given by `#[enumoid(order = N)]`.
+  order: Option<syn::LitInt>,
+  /// Whether the variant is excluded by `#[enumoid(skip)]`.
+  skip: bool,
+}
+
+fn get_variant_options(variant: &syn::Variant) -> Result<VariantOptions> {
+  let mut options = VariantOptions::default();
+  parse_enumoid_attrs(&variant.attrs, |meta| {
+    if meta.path.is_ident("order") {
+      options.order = Some(meta.value()?.parse()?);
+      Ok(true)
+    } else if meta.path.is_ident("skip") {
+      options.skip = true;
+      Ok(true)
+    } else {
+      Ok(false)
+    }
+  })?;
+  if let (Some(order), true) = (&options.order, options.skip) {
+    return Err(syn::Error::new_spanned(
+      order,
+      "Skipped variants may not have an order.",
+    ));
+  }
+  Ok(options)
+}
+
+/// Returns true if a variant has no `enumoid` attributes which change its
+/// index.
+fn has_default_index(variant: &syn::Variant) -> bool {
+  matches!(
+    get_variant_options(variant),
+    Ok(VariantOptions {
+      order: None,
+      skip: false
+    })
+  )
+}
+
+/// Returns true if a field has an `#[enumoid(reverse)]` attribute.
+fn get_field_reverse(field: &syn::Field) -> Result<bool> {
+  let mut reverse = false;
+  parse_enumoid_attrs(&field.attrs, |meta| {
+    reverse |= meta.path.is_ident("reverse");
+    Ok(meta.path.is_ident("reverse"))
+  })?;
+  Ok(reverse)
+}
+
+/// Returns the variants of an enum which are not skipped, in the order given
+/// by their `#[enumoid(order = N)]` attributes or in declaration order if
+/// they have none.
+fn get_ordered_variants(data: &syn::DataEnum) -> Result<Vec<&syn::Variant>> {
+  let mut errors = Errors::default();
+  let mut variants = Vec::new();
+  let mut orders = Vec::new();
+  for variant in data.variants.iter() {
+    if let Some(options) = errors.check(get_variant_options(variant)) {
+      if !options.skip {
+        variants.push(variant);
+        orders.push(options.order);
+      }
+    }
+  }
+  if orders.iter().all(Option::is_none) {
+    return errors.finish(Ok(variants));
+  }
+  let mut slots = vec![None; orders.len()];
+  for (variant, order) in variants.into_iter().zip(orders) {
+    let Some(order) = order else {
+      errors.push(syn::Error::new_spanned(
+        &variant.ident,
+        "Every variant must have an order if any do.",
+      ));
+      continue;
+    };
+    match errors.check(order.base10_parse::<usize>()) {
+      Some(index) if index >= slots.len() => {
+        errors.push(syn::Error::new_spanned(
+          &order,
+          "Order must be less than the number of variants.",
+        ));
+      }
+      Some(index) if slots[index].is_some() => {
+        errors.push(syn::Error::new_spanned(
+          &order,
+          "Order is used by more than one variant.",
+        ));
+      }
+      Some(index) => slots[index] = Some(variant),
+      None => {}
+    }
+  }
+  errors.finish(Ok(slots.into_iter().flatten().collect()))
+}
+
+/// Returns the conversions for the field of a compound variant or struct: a
+/// function mapping between the field's index and its position within the
+/// compound, and the first and last field values.
+fn get_field_order(
+  field: &syn::Field,
+  target: &Target,
+  krate: &syn::Path,
+) -> Result<(
+  impl Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+  proc_macro2::TokenStream,
+  proc_macro2::TokenStream,
+)> {
+  let reverse = get_field_reverse(field)?;
+  let sub_ty = field.ty.clone();
+  let first = quote! { <#sub_ty as #krate::Enumoid>::FIRST };
+  let last = quote! { <#sub_ty as #krate::Enumoid>::LAST };
+  let size_word = quote! { <#sub_ty as #krate::Enumoid>::SIZE_WORD };
+  let target_generic = target.generic;
+  let krate = krate.clone();
+  let flip = move |word: proc_macro2::TokenStream| {
+    if !reverse {
+      word
+    } else if target_generic {
+      quote! {
+        <<#sub_ty as #krate::Enumoid>::Word as #krate::RawSizeWord>::from_usize_unchecked(
+          #krate::RawSizeWord::as_(#size_word) - 1 - #krate::RawSizeWord::as_(#word)
+        )
+      }
+    } else {
+      quote! { (#size_word - 1 - (#word)) }
+    }
+  };
+  Ok(if reverse {
+    (flip, last, first)
+  } else {
+    (flip, first, last)
+  })
+}
+
+/// Returns the integer representation given by a `repr` attribute, if any.
+fn get_int_repr(input: &syn::DeriveInput) -> Option<syn::Ident> {
+  let reprs = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+    "i128", "isize",
+  ];
+  input
+    .attrs
+    .iter()
+    .filter(|attr| attr.path().is_ident("repr"))
+    .filter_map(|attr| {
+      attr
+        .parse_args_with(
+          syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+        )
+        .ok()
+    })
+    .flatten()
+    .find_map(|meta| match meta {
+      syn::Meta::Path(path) => path
+        .get_ident()
+        .filter(|ident| reprs.iter().any(|repr| ident == repr))
+        .cloned(),
+      _ => None,
+    })
+}
+
+/// Returns true if the discriminants of an enum's variants are their indices.
+fn has_index_discriminants(data: &syn::DataEnum) -> bool {
+  data.variants.iter().enumerate().all(|(index, variant)| {
+    match &variant.discriminant {
+      None => true,
+      Some((
+        _,
+        syn::Expr::Lit(syn::ExprLit {
+          lit: syn::Lit::Int(lit),
+          ..
+        }),
+      )) => lit.base10_parse::<usize>().ok() == Some(index),
+      Some(_) => false,
+    }
+  })
+}
+
+/// Generates conversions between a fieldless enum and its discriminants.
+fn generate_discriminant_conversions(
+  data: &syn::DataEnum,
+  name: &syn::Ident,
+  repr: &syn::Ident,
+  krate: &syn::Path,
+) -> proc_macro2::TokenStream {
+  let variants: Vec<&syn::Ident> =
+    data.variants.iter().map(|v| &v.ident).collect();
+  let consts: Vec<syn::Ident> = (0..variants.len())
+    .map(|index| format_ident!("D{}", index))
+    .collect();
+  quote! {
+    impl #krate::EnumDiscriminant for #name {
+      type Repr = #repr;
+      #[inline]
+      fn into_discriminant(self) -> #repr {
+        self as #repr
+      }
+      #[inline]
+      fn from_discriminant(
+        value: #repr,
+      ) -> ::core::result::Result<Self, #krate::DiscriminantError<#repr>> {
+        #(
+          const #consts: #repr = #name::#variants as #repr;
+        )*
+        match value {
+          #(
+            #consts => ::core::result::Result::Ok(#name::#variants),
+          )*
+          _ => ::core::result::Result::Err(
+            #krate::DiscriminantError::new(value),
+          ),
+        }
+      }
+    }
+    impl ::core::convert::From<#name> for #repr {
+      #[inline]
+      fn from(value: #name) -> Self {
+        value as #repr
+      }
+    }
+    impl ::core::convert::TryFrom<#repr> for #name {
+      type Error = #krate::DiscriminantError<#repr>;
+      #[inline]
+      fn try_from(
+        value: #repr,
+      ) -> ::core::result::Result<Self, Self::Error> {
+        <Self as #krate::EnumDiscriminant>::from_discriminant(value)
+      }
+    }
+  }
+}
+
 struct Rule {
+  /// The type of the field, if any.
+  field: Option<syn::Type>,
   size: proc_macro2::TokenStream,
   consts: proc_macro2::TokenStream,
   to_expr: proc_macro2::TokenStream,
   from_expr: proc_macro2::TokenStream,
+  /// Statement which returns the value for `value` if it is in range, used
+  /// instead of `from_expr` by generic Enumoids.
+  from_stmt: proc_macro2::TokenStream,
   first: proc_macro2::TokenStream,
   last: proc_macro2::TokenStream,
 }
 
+/// Names the type being derived and how the range bounds are bound. Generic
+/// Enumoids can't use inner `const` items, which may not refer to generic
+/// parameters, so they bind the bounds with `let` instead.
+struct Target<'a> {
+  name: &'a syn::Ident,
+  self_ty: proc_macro2::TokenStream,
+  binding: proc_macro2::TokenStream,
+  generic: bool,
+}
+
+impl Target<'_> {
+  fn bind(
+    &self,
+    ident: &syn::Ident,
+    krate: &syn::Path,
+    value: proc_macro2::TokenStream,
+  ) -> proc_macro2::TokenStream {
+    let Target {
+      self_ty, binding, ..
+    } = self;
+    quote! { #binding #ident: <#self_ty as #krate::Enumoid>::Word = #value; }
+  }
+
+  /// Converts an index word to the word type `ty`. The word types of generic
+  /// fields are unknown, so they are converted through `usize`.
+  fn cast(
+    &self,
+    value: proc_macro2::TokenStream,
+    ty: proc_macro2::TokenStream,
+    krate: &syn::Path,
+  ) -> proc_macro2::TokenStream {
+    if self.generic {
+      quote! {
+        <#ty as #krate::RawSizeWord>::from_usize_unchecked(
+          #krate::RawSizeWord::as_(#value)
+        )
+      }
+    } else {
+      quote! { (#value) as #ty }
+    }
+  }
+}
+
 fn generate_enum_rules(
   data: &syn::DataEnum,
-  name: &syn::Ident,
+  target: &Target,
+  krate: &syn::Path,
 ) -> Result<Vec<Rule>> {
+  let self_ty = &target.self_ty;
   let mut next = quote! { 0 };
   let mut rules = Vec::new();
-  for (index, variant) in data.variants.iter().enumerate() {
+  let mut errors = Errors::default();
+  let variants = errors.check(get_ordered_variants(data));
+  if variants.as_ref().is_some_and(Vec::is_empty) {
+    errors.push(syn::Error::new_spanned(
+      target.name,
+      "Enumoids must have at least one variant which is not skipped.",
+    ));
+  }
+  let variants = variants.unwrap_or_default();
+  for (index, variant) in variants.into_iter().enumerate() {
     let v_name = &variant.ident;
     let kns = format_ident!("K{}S", index.to_string());
     rules.push(if let Some(field) = variant.fields.iter().next() {
       if variant.fields.len() > 1 {
-        bail!("Enumoid variants may not have more than one field.");
+        errors.push(syn::Error::new_spanned(
+          &variant.fields,
+          "Enumoid variants may not have more than one field.",
+        ));
+        continue;
       }
       if field.ident.is_some() {
-        bail!("Enumoid variants may not use a named field.")
+        errors.push(syn::Error::new_spanned(
+          &variant.fields,
+          "Enumoid variants may not use a named field.",
+        ));
+        continue;
       }
+      let Some((flip, first, last)) =
+        errors.check(get_field_order(field, target, krate))
+      else {
+        continue;
+      };
       let kne = format_ident!("K{}E", index.to_string());
       let sub_ty = field.ty.clone();
+      let word = quote! { <#self_ty as #krate::Enumoid>::Word };
+      let sub_word = quote! { <#sub_ty as #krate::Enumoid>::Word };
+      let to_word = target.cast(
+        flip(quote! { <#sub_ty as #krate::Enumoid>::into_word(x) }),
+        word.clone(),
+        krate,
+      );
+      let from_word =
+        flip(target.cast(quote! { x-#kns }, sub_word.clone(), krate));
+      let from_value_word =
+        flip(target.cast(quote! { value-#kns }, sub_word, krate));
+      let size_word = target.cast(
+        quote! { <#sub_ty as #krate::Enumoid>::SIZE_WORD },
+        word,
+        krate,
+      );
       let curr = next;
-      next = quote! { #kns + <#sub_ty as enumoid::Enumoid>::SIZE_WORD as <#name as enumoid::Enumoid>::Word };
+      next = quote! { #kns + #size_word };
+      let start_const = target.bind(&kns, krate, curr);
+      let end_const = target.bind(&kne, krate, quote! { #next - 1 });
       Rule {
-        size: quote! { <#sub_ty as enumoid::Enumoid>::SIZE },
+        size: quote! { <#sub_ty as #krate::Enumoid>::SIZE },
         consts: quote! {
-          const #kns: <#name as enumoid::Enumoid>::Word = #curr;
-          const #kne: <#name as enumoid::Enumoid>::Word = #next - 1;
+          #start_const
+          #end_const
         },
-        to_expr: quote! { Self::#v_name(x) => #kns + x.into_word() as <#name as enumoid::Enumoid>::Word, },
-        from_expr: quote! { x@#kns..=#kne => Self::#v_name(#sub_ty::from_word_unchecked((x-#kns) as <#sub_ty as enumoid::Enumoid>::Word)), },
-        first: quote! { Self::#v_name(#sub_ty::FIRST) },
-        last: quote! { Self::#v_name(#sub_ty::LAST) },
+        to_expr: quote! { Self::#v_name(x) => #kns + #to_word, },
+        from_expr: quote! { x@#kns..=#kne => Self::#v_name(<#sub_ty as #krate::Enumoid>::from_word_unchecked(#from_word)), },
+        from_stmt: quote! {
+          if value <= #kne {
+            return Self::#v_name(<#sub_ty as #krate::Enumoid>::from_word_unchecked(#from_value_word));
+          }
+        },
+        first: quote! { Self::#v_name(#first) },
+        last: quote! { Self::#v_name(#last) },
+        field: Some(sub_ty),
       }
     } else {
       let rule = Rule {
+        field: None,
         size: quote! { 1 },
-        consts: quote! { const #kns: <#name as enumoid::Enumoid>::Word = #next; },
+        consts: target.bind(&kns, krate, next),
         to_expr: quote! { Self::#v_name => #kns, },
         from_expr: quote! { #kns => Self::#v_name, },
+        from_stmt: quote! {
+          if value == #kns {
+            return Self::#v_name;
+          }
+        },
         first: quote! { Self::#v_name },
         last: quote! { Self::#v_name },
       };
@@ -121,35 +542,58 @@ fn generate_enum_rules(
       rule
     });
   }
-  Ok(rules)
+  errors.finish(Ok(rules))
 }
 
 fn generate_struct_rules(
   data: &syn::DataStruct,
-  name: &syn::Ident,
+  target: &Target,
+  krate: &syn::Path,
 ) -> Result<Vec<Rule>> {
+  let Target { name, self_ty, .. } = target;
   let rule = if let Some(field) = data.fields.iter().next() {
     if data.fields.len() > 1 {
-      bail!("Enumoid structs may not have more than one field.");
+      return Err(syn::Error::new_spanned(
+        &data.fields,
+        "Enumoid structs may not have more than one field.",
+      ));
     }
     if field.ident.is_some() {
-      bail!("Enumoid structs may not use a named field.")
+      return Err(syn::Error::new_spanned(
+        &data.fields,
+        "Enumoid structs may not use a named field.",
+      ));
     }
+    let (flip, first, last) = get_field_order(field, target, krate)?;
     let sub_ty = field.ty.clone();
+    let sub_word = quote! { <#sub_ty as #krate::Enumoid>::Word };
+    let to_word = target.cast(
+      flip(quote! { <#sub_ty as #krate::Enumoid>::into_word(x) }),
+      quote! { <#self_ty as #krate::Enumoid>::Word },
+      krate,
+    );
+    let from_word = flip(target.cast(quote! { x }, sub_word.clone(), krate));
+    let from_value_word = flip(target.cast(quote! { value }, sub_word, krate));
     Rule {
-      size: quote! { <#sub_ty as enumoid::Enumoid>::SIZE },
+      size: quote! { <#sub_ty as #krate::Enumoid>::SIZE },
       consts: quote! {},
-      to_expr: quote! { #name(x) => x.into_word() as <#name as enumoid::Enumoid>::Word, },
-      from_expr: quote! { x => #name(#sub_ty::from_word_unchecked((x) as <#sub_ty as enumoid::Enumoid>::Word)), },
-      first: quote! { #name(<#sub_ty as enumoid::Enumoid>::FIRST) },
-      last: quote! { #name(<#sub_ty as enumoid::Enumoid>::LAST) },
+      to_expr: quote! { #name(x) => #to_word, },
+      from_expr: quote! { x => #name(<#sub_ty as #krate::Enumoid>::from_word_unchecked(#from_word)), },
+      from_stmt: quote! {
+        return #name(<#sub_ty as #krate::Enumoid>::from_word_unchecked(#from_value_word));
+      },
+      first: quote! { #name(#first) },
+      last: quote! { #name(#last) },
+      field: Some(sub_ty),
     }
   } else {
     Rule {
+      field: None,
       size: quote! { 1 },
       consts: quote! {},
       to_expr: quote! { #name => 0, },
       from_expr: quote! { 0 => #name, },
+      from_stmt: quote! { return #name; },
       first: quote! { #name },
       last: quote! { #name },
     }
@@ -160,24 +604,116 @@ fn generate_struct_rules(
 fn try_derive_enumoid(
   input: proc_macro::TokenStream,
 ) -> Result<proc_macro2::TokenStream> {
-  let input: syn::DeriveInput = syn::parse(input).unwrap();
-  let word_type = get_index_type(&input)?;
-  let word_type_error = format!("Index type '{word_type}' is too narrow.");
-  let bitset_word_types = get_bitset_word_types(&input)?;
+  let input: syn::DeriveInput = syn::parse(input)?;
+  let mut errors = Errors::default();
+  // Fall back to the defaults after an error so that the remaining checks can
+  // still report theirs. No code is generated in that case.
+  let word_type = errors.check(get_index_type(&input)).unwrap_or_default();
+  let bitset_word_types = errors
+    .check(get_bitset_word_types(&input))
+    .unwrap_or_default();
+  let TypeOptions {
+    krate,
+    discriminant,
+    partial,
+  } = errors.check(get_type_options(&input)).unwrap_or_default();
+  let repr = get_int_repr(&input);
   let name = input.ident;
-  let rules = if let syn::Data::Enum(data_enum) = input.data {
-    if data_enum.variants.is_empty() {
-      Err(anyhow!("Enumoids must be inhabited by at least one value."))
-    } else {
-      generate_enum_rules(&data_enum, &name)
-    }
-  } else if let syn::Data::Struct(data_struct) = input.data {
-    generate_struct_rules(&data_struct, &name)
+  let generic = !input.generics.params.is_empty();
+  if generic && word_type.is_none() {
+    errors.push(syn::Error::new_spanned(
+      &input.generics,
+      "index_type(auto) is not supported for generic Enumoids.",
+    ));
+  }
+  let (self_ty, binding) = if generic {
+    (quote! { Self }, quote! { let })
   } else {
-    Err(anyhow!(
-      "#[derive(Enumoid)] must be applied to an enum or struct."
-    ))
-  }?;
+    (quote! { #name }, quote! { const })
+  };
+  let target = Target {
+    name: &name,
+    self_ty,
+    binding,
+    generic,
+  };
+  // Fieldless enums opting in with `#[enumoid(discriminant)]` get conversions
+  // through the discriminant, whose type is `isize` without a `repr`. Those
+  // with an integer representation whose discriminants are the indices also
+  // convert indices with casts.
+  let (discriminants, cast_repr) = match &input.data {
+    syn::Data::Enum(data_enum)
+      if data_enum
+        .variants
+        .iter()
+        .all(|variant| matches!(variant.fields, syn::Fields::Unit)) =>
+    {
+      let discriminant_type =
+        repr.clone().unwrap_or_else(|| format_ident!("isize"));
+      (
+        discriminant.is_some().then(|| {
+          generate_discriminant_conversions(
+            data_enum,
+            &name,
+            &discriminant_type,
+            &krate,
+          )
+        }),
+        repr.filter(|_| {
+          has_index_discriminants(data_enum)
+            && data_enum.variants.iter().all(has_default_index)
+        }),
+      )
+    }
+    _ => {
+      if let Some(path) = &discriminant {
+        errors.push(syn::Error::new_spanned(
+          path,
+          "Only fieldless enums have discriminants.",
+        ));
+      }
+      (None, None)
+    }
+  };
+  let skipped: Vec<&syn::Ident> = match &input.data {
+    syn::Data::Enum(data_enum) => data_enum
+      .variants
+      .iter()
+      .filter(|v| get_variant_options(v).is_ok_and(|options| options.skip))
+      .map(|v| &v.ident)
+      .collect(),
+    _ => Vec::new(),
+  };
+  // Values of skipped variants make the infallible conversions panic, so the
+  // type must opt into this.
+  if !partial {
+    for variant in &skipped {
+      errors.push(syn::Error::new_spanned(
+        variant,
+        "Skipped variants require `#[enumoid(partial)]` on the type, as \
+         `into_word` panics on their values.",
+      ));
+    }
+  }
+  let rules = match &input.data {
+    syn::Data::Enum(data_enum) if data_enum.variants.is_empty() => {
+      Err(syn::Error::new_spanned(
+        &name,
+        "Enumoids must be inhabited by at least one value.",
+      ))
+    }
+    syn::Data::Enum(data_enum) => {
+      generate_enum_rules(data_enum, &target, &krate)
+    }
+    syn::Data::Struct(data_struct) => {
+      generate_struct_rules(data_struct, &target, &krate)
+    }
+    syn::Data::Union(data_union) => Err(syn::Error::new(
+      data_union.union_token.span(),
+      "#[derive(Enumoid)] must be applied to an enum or struct.",
+    )),
+  };
+  let rules = errors.finish(rules)?;
   let size = rules
     .iter()
     .map(|r| r.size.clone())
@@ -186,34 +722,169 @@ fn try_derive_enumoid(
     rules.iter().map(|r| &r.consts).collect();
   let to_exprs = rules.iter().map(|r| &r.to_expr);
   let from_exprs = rules.iter().map(|r| &r.from_expr);
+  let from_stmts = rules.iter().map(|r| &r.from_stmt);
+  // The discriminants are the indices, so every index below `SIZE` can be
+  // transmuted into a valid value.
+  let (into_word, from_word) = match cast_repr {
+    Some(repr) => (
+      quote! { self as Self::Word },
+      quote! { unsafe { ::core::mem::transmute::<#repr, Self>(value as #repr) } },
+    ),
+    None if generic => (
+      quote! {
+        #(
+          #consts
+        )*
+        match self {
+          #(
+            #to_exprs
+          )*
+          #(
+            Self::#skipped { .. } => panic!("Skipped values have no index."),
+          )*
+        }
+      },
+      quote! {
+        #(
+          #consts
+        )*
+        #(
+          #from_stmts
+        )*
+        unsafe { ::core::hint::unreachable_unchecked() }
+      },
+    ),
+    None => (
+      quote! {
+        #(
+          #consts
+        )*
+        match self {
+          #(
+            #to_exprs
+          )*
+          #(
+            Self::#skipped { .. } => panic!("Skipped values have no index."),
+          )*
+        }
+      },
+      quote! {
+        #(
+          #consts
+        )*
+        match value {
+          #(
+            #from_exprs
+          )*
+          _ => unsafe { ::core::hint::unreachable_unchecked() }
+        }
+      },
+    ),
+  };
+  let try_into_word = (!skipped.is_empty()).then(|| {
+    quote! {
+      #[inline]
+      fn try_into_word(self) -> ::core::option::Option<Self::Word> {
+        match self {
+          #(
+            Self::#skipped { .. } => ::core::option::Option::None,
+          )*
+          _ => ::core::option::Option::Some(
+            <Self as #krate::Enumoid>::into_word(self),
+          ),
+        }
+      }
+    }
+  });
   let first = &rules.first().unwrap().first;
   let last = &rules.last().unwrap().last;
+  let (word_type, size_word, last_word) = match word_type {
+    Some(word_type) => {
+      let word_type_error = format!("Index type '{word_type}' is too narrow.");
+      (
+        word_type.clone(),
+        quote! {
+          if Self::SIZE <= #word_type::MAX as usize {
+            Self::SIZE as Self::Word
+          }
+          else
+          {
+            panic!(#word_type_error);
+          }
+        },
+        quote! {
+          if Self::SIZE - 1 <= #word_type::MAX as usize {
+            (Self::SIZE - 1) as Self::Word
+          }
+          else
+          {
+            panic!(#word_type_error);
+          }
+        },
+      )
+    }
+    // The size is computed from the fields alone, so selecting the word type
+    // from it does not depend on this impl.
+    None => (
+      quote! {
+        <#krate::AutoWord<{ #krate::auto_word_tier(#size) }>
+          as #krate::AutoWordHelper>::Word
+      },
+      quote! { Self::SIZE as Self::Word },
+      quote! { (Self::SIZE - 1) as Self::Word },
+    ),
+  };
+  // `EnumSize<Self>` needs an index word which can represent `SIZE + 1`. The
+  // size of a generic type is unknown, so the next wider word is used.
+  let size_word_type = if generic {
+    quote! { <#word_type as #krate::RawSizeWord>::Wider }
+  } else {
+    quote! {
+      <#krate::AutoWord<{ #krate::auto_word_tier(#size + 1) }>
+        as #krate::AutoWordHelper>::Word
+    }
+  };
+  let field_tys: Vec<&syn::Type> =
+    rules.iter().filter_map(|r| r.field.as_ref()).collect();
+  let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+  let enumoid_where =
+    generic_bounds(&input.generics, &field_tys, quote! { #krate::Enumoid });
+  let allow_bindings = generic.then(|| {
+    quote! { #[allow(non_snake_case, unreachable_code, unused_variables)] }
+  });
+  let helper_impls = if generic {
+    generate_generic_helpers(
+      &input.generics,
+      &name,
+      &rules,
+      &bitset_word_types,
+      &krate,
+    )
+  } else {
+    generate_helpers(&name, &bitset_word_types, &krate)
+  };
   Ok(quote! {
-    impl enumoid::Enumoid for #name {
+    #discriminants
+    impl #impl_generics #krate::Enumoid for #name #ty_generics #enumoid_where {
       type Word = #word_type;
-      type WordRange = std::ops::Range<Self::Word>;
+      type WordRange = ::core::ops::Range<Self::Word>;
       const SIZE: usize = #size;
-      const SIZE_WORD: Self::Word = if Self::SIZE <= #word_type::MAX as usize {
-        Self::SIZE as Self::Word
-      }
-      else
-      {
-        panic!(#word_type_error);
-      };
+      const SIZE_WORD: Self::Word = #size_word;
+      const LAST_WORD: Self::Word = #last_word;
+      type SizeWord = #size_word_type;
+      const SIZES_LAST_WORD: Self::SizeWord = Self::SIZE as Self::SizeWord;
+      const SIZES_SIZE_WORD: Self::SizeWord =
+        (Self::SIZE + 1) as Self::SizeWord;
       const FIRST: Self = #first;
       const LAST: Self = #last;
       #[inline]
+      #allow_bindings
       fn into_word(self) -> Self::Word {
-        #(
-          #consts
-        )*
-        match self {
-          #(
-            #to_exprs
-          )*
-        }
+        #into_word
       }
+      #try_into_word
       #[inline]
+      #allow_bindings
       unsafe fn from_word_unchecked(value: Self::Word) -> Self {
         debug_assert!(
           value < Self::SIZE_WORD,
@@ -221,34 +892,68 @@ fn try_derive_enumoid(
           value,
           Self::SIZE_WORD
         );
-        #(
-          #consts
-        )*
-        match value {
-          #(
-            #from_exprs
-          )*
-          _ => unsafe { std::hint::unreachable_unchecked() }
-        }
+        #from_word
       }
       #[inline]
       fn word_range(base: Self::Word, lim: Self::Word) -> Self::WordRange {
         base..lim
       }
     }
-    impl<V> enumoid::EnumArrayHelper<V> for #name {
-      type PartialArray = [std::mem::MaybeUninit<V>; <Self as enumoid::Enumoid>::SIZE];
-      type TotalArray = [V; <Self as enumoid::Enumoid>::SIZE];
+    #helper_impls
+    impl #impl_generics ::core::convert::From<#krate::EnumIndex<#name #ty_generics>>
+      for #name #ty_generics #enumoid_where
+    {
+      #[inline]
+      fn from(index: #krate::EnumIndex<Self>) -> Self {
+        index.into_value()
+      }
+    }
+  })
+}
+
+/// Returns the where clause of a generic type extended with a bound on each
+/// of its field types.
+fn generic_bounds(
+  generics: &syn::Generics,
+  field_tys: &[&syn::Type],
+  bound: proc_macro2::TokenStream,
+) -> Option<syn::WhereClause> {
+  if generics.params.is_empty() {
+    return generics.where_clause.clone();
+  }
+  let mut where_clause = generics
+    .where_clause
+    .clone()
+    .unwrap_or_else(|| syn::parse_quote! { where });
+  for ty in field_tys {
+    where_clause
+      .predicates
+      .push(syn::parse_quote! { #ty: #bound });
+  }
+  Some(where_clause)
+}
+
+/// Generates the helper trait impls for a non-generic type, which use arrays
+/// of length `SIZE`.
+fn generate_helpers(
+  name: &syn::Ident,
+  bitset_word_types: &[proc_macro2::TokenStream],
+  krate: &syn::Path,
+) -> proc_macro2::TokenStream {
+  quote! {
+    impl<V> #krate::EnumArrayHelper<V> for #name {
+      type PartialArray = [::core::mem::MaybeUninit<V>; <Self as #krate::Enumoid>::SIZE];
+      type TotalArray = [V; <Self as #krate::Enumoid>::SIZE];
       #[inline(always)]
       fn partial_slice(p: &Self::PartialArray)
-        -> &[std::mem::MaybeUninit<V>] { p }
+        -> &[::core::mem::MaybeUninit<V>] { p }
       #[inline(always)]
       fn partial_slice_mut(p: &mut Self::PartialArray)
-        -> &mut [std::mem::MaybeUninit<V>] { p }
+        -> &mut [::core::mem::MaybeUninit<V>] { p }
       #[inline]
       unsafe fn partial_to_total(p: Self::PartialArray)
         -> Self::TotalArray {
-        std::ptr::read(&p as *const _ as *const Self::TotalArray)
+        ::core::ptr::read(&p as *const _ as *const Self::TotalArray)
       }
       #[inline(always)]
       fn total_slice(t: &Self::TotalArray) -> &[V] { t }
@@ -258,52 +963,283 @@ fn try_derive_enumoid(
       fn total_to_partial(t: Self::TotalArray)
         -> Self::PartialArray {
         let p = unsafe {
-          std::ptr::read(&t as *const _ as *const Self::PartialArray)
+          ::core::ptr::read(&t as *const _ as *const Self::PartialArray)
         };
-        std::mem::forget(t);
+        ::core::mem::forget(t);
         p
       }
     }
+    impl<P> #krate::EnumPackHelper<P> for #name {
+      type PlaneArray = [P; (usize::BITS - (<Self as #krate::Enumoid>::SIZE - 1).leading_zeros()) as usize];
+      #[inline(always)]
+      fn plane_slice(p: &Self::PlaneArray) -> &[P] { p }
+      #[inline(always)]
+      fn plane_slice_mut(p: &mut Self::PlaneArray) -> &mut [P] { p }
+      #[inline]
+      fn new_planes<F: FnMut() -> P>(mut f: F) -> Self::PlaneArray {
+        ::core::array::from_fn(|_| f())
+      }
+    }
     #(
-      impl enumoid::EnumSetHelper<#bitset_word_types> for #name {
+      impl #krate::EnumSetHelper<#bitset_word_types> for #name {
         type BitsetWord = #bitset_word_types;
-        type BitsetArray = [#bitset_word_types; <Self as enumoid::EnumSetHelper<#bitset_word_types>>::BITSET_WORDS];
+        type BitsetArray = [#bitset_word_types; <Self as #krate::EnumSetHelper<#bitset_word_types>>::BITSET_WORDS];
         const BITSET_WORD_BITS: usize = <#bitset_word_types>::BITS as usize;
-        const DEFAULT_BITSET: Self::BitsetArray = [0; <Self as enumoid::EnumSetHelper<#bitset_word_types>>::BITSET_WORDS];
+        const DEFAULT_BITSET: Self::BitsetArray = [0; <Self as #krate::EnumSetHelper<#bitset_word_types>>::BITSET_WORDS];
         #[inline(always)]
         fn slice_bitset(arr: &Self::BitsetArray) -> &[#bitset_word_types] { arr }
         #[inline(always)]
         fn slice_bitset_mut(arr: &mut Self::BitsetArray) -> &mut [#bitset_word_types] { arr }
       }
     )*
-    impl core::convert::From<enumoid::EnumIndex<#name>> for #name {
+  }
+}
+
+/// Nests a non-empty list of array types or values with `ArrayConcat`.
+fn concat(
+  krate: &syn::Path,
+  mut parts: Vec<proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+  let last = parts.pop().expect("at least one part");
+  parts.into_iter().rev().fold(
+    last,
+    |rest, part| quote! { #krate::ArrayConcat<#part, #rest> },
+  )
+}
+
+/// Nests a non-empty list of array values with `ArrayConcat`.
+fn concat_values(
+  krate: &syn::Path,
+  mut parts: Vec<proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+  let last = parts.pop().expect("at least one part");
+  parts.into_iter().rev().fold(
+    last,
+    |rest, part| quote! { #krate::ArrayConcat(#part, #rest) },
+  )
+}
+
+/// Generates the helper trait impls for a generic type. Array lengths may not
+/// depend on generic parameters, so the arrays are built by concatenating an
+/// array for the unit variants with the arrays of the field types.
+fn generate_generic_helpers(
+  generics: &syn::Generics,
+  name: &syn::Ident,
+  rules: &[Rule],
+  bitset_word_types: &[proc_macro2::TokenStream],
+  krate: &syn::Path,
+) -> proc_macro2::TokenStream {
+  let (generics_impl, ty_generics, _) = generics.split_for_impl();
+  let field_tys: Vec<&syn::Type> =
+    rules.iter().filter_map(|r| r.field.as_ref()).collect();
+  let units = rules.iter().filter(|r| r.field.is_none()).count();
+  let with_param = |param: syn::GenericParam| {
+    let mut generics = generics.clone();
+    generics.params.push(param);
+    generics
+  };
+  // Builds the component types of an array, with `unit` giving the array for
+  // the unit variants and `field` the array of a field type.
+  let parts =
+    |unit: &dyn Fn() -> proc_macro2::TokenStream,
+     field: &dyn Fn(&syn::Type) -> proc_macro2::TokenStream| {
+      let mut parts = Vec::new();
+      if units > 0 {
+        parts.push(unit());
+      }
+      parts.extend(field_tys.iter().map(|ty| field(ty)));
+      parts
+    };
+
+  let value_generics = with_param(syn::parse_quote! { __EnumoidValue });
+  let (value_impl_generics, _, _) = value_generics.split_for_impl();
+  let value_where = generic_bounds(
+    generics,
+    &field_tys,
+    quote! { #krate::EnumArrayHelper<__EnumoidValue> },
+  );
+  let partial_array = concat(
+    krate,
+    parts(
+      &|| quote! { [::core::mem::MaybeUninit<__EnumoidValue>; #units] },
+      &|ty| quote! { <#ty as #krate::EnumArrayHelper<__EnumoidValue>>::PartialArray },
+    ),
+  );
+  let total_array = concat(
+    krate,
+    parts(
+      &|| quote! { [__EnumoidValue; #units] },
+      &|ty| quote! { <#ty as #krate::EnumArrayHelper<__EnumoidValue>>::TotalArray },
+    ),
+  );
+
+  // A sum of values needs at most one more plane than the sum of the planes
+  // needed by its parts, for each addition.
+  let plane_generics = with_param(syn::parse_quote! { __EnumoidPlane });
+  let (plane_impl_generics, _, _) = plane_generics.split_for_impl();
+  let plane_where = generic_bounds(
+    generics,
+    &field_tys,
+    quote! { #krate::EnumPackHelper<__EnumoidPlane> },
+  );
+  let unit_planes =
+    (usize::BITS - units.saturating_sub(1).leading_zeros()) as usize;
+  let mut plane_parts = parts(
+    &|| quote! { [__EnumoidPlane; #unit_planes] },
+    &|ty| quote! { <#ty as #krate::EnumPackHelper<__EnumoidPlane>>::PlaneArray },
+  );
+  let carry_planes = plane_parts.len() - 1;
+  plane_parts.push(quote! { [__EnumoidPlane; #carry_planes] });
+  let plane_array = concat(krate, plane_parts);
+  let mut new_plane_parts = parts(
+    &|| quote! { ::core::array::from_fn(|_| f()) },
+    &|ty| quote! { <#ty as #krate::EnumPackHelper<__EnumoidPlane>>::new_planes(&mut f) },
+  );
+  new_plane_parts.push(quote! { ::core::array::from_fn(|_| f()) });
+  let new_planes = concat_values(krate, new_plane_parts);
+
+  // Likewise, a sum of values needs at most as many bitset words as the sum of
+  // the words needed by its parts.
+  let bitset_impls = bitset_word_types.iter().map(|word| {
+    let bitset_where = generic_bounds(
+      generics,
+      &field_tys,
+      quote! { #krate::EnumSetHelper<#word> },
+    );
+    let bitset_array = concat(
+      krate,
+      parts(
+        &|| quote! { [#word; #units.div_ceil(<#word>::BITS as usize)] },
+        &|ty| quote! { <#ty as #krate::EnumSetHelper<#word>>::BitsetArray },
+      ),
+    );
+    let default_bitset = concat_values(
+      krate,
+      parts(
+        &|| quote! { [0; #units.div_ceil(<#word>::BITS as usize)] },
+        &|ty| quote! { <#ty as #krate::EnumSetHelper<#word>>::DEFAULT_BITSET },
+      ),
+    );
+    quote! {
+      impl #generics_impl #krate::EnumSetHelper<#word> for #name #ty_generics #bitset_where {
+        type BitsetWord = #word;
+        type BitsetArray = #bitset_array;
+        const BITSET_WORD_BITS: usize = <#word>::BITS as usize;
+        const DEFAULT_BITSET: Self::BitsetArray = #default_bitset;
+        #[inline(always)]
+        fn slice_bitset(arr: &Self::BitsetArray) -> &[#word] {
+          unsafe {
+            #krate::concat_slice(arr, <Self as #krate::EnumSetHelper<#word>>::BITSET_WORDS)
+          }
+        }
+        #[inline(always)]
+        fn slice_bitset_mut(arr: &mut Self::BitsetArray) -> &mut [#word] {
+          unsafe {
+            #krate::concat_slice_mut(arr, <Self as #krate::EnumSetHelper<#word>>::BITSET_WORDS)
+          }
+        }
+      }
+    }
+  });
+
+  quote! {
+    impl #value_impl_generics #krate::EnumArrayHelper<__EnumoidValue>
+      for #name #ty_generics #value_where
+    {
+      type PartialArray = #partial_array;
+      type TotalArray = #total_array;
+      #[inline(always)]
+      fn partial_slice(p: &Self::PartialArray)
+        -> &[::core::mem::MaybeUninit<__EnumoidValue>] {
+        unsafe { #krate::concat_slice(p, <Self as #krate::Enumoid>::SIZE) }
+      }
+      #[inline(always)]
+      fn partial_slice_mut(p: &mut Self::PartialArray)
+        -> &mut [::core::mem::MaybeUninit<__EnumoidValue>] {
+        unsafe { #krate::concat_slice_mut(p, <Self as #krate::Enumoid>::SIZE) }
+      }
       #[inline]
-      fn from(index: enumoid::EnumIndex<#name>) -> Self {
-        index.into_value()
+      unsafe fn partial_to_total(p: Self::PartialArray)
+        -> Self::TotalArray {
+        ::core::ptr::read(&p as *const _ as *const Self::TotalArray)
+      }
+      #[inline(always)]
+      fn total_slice(t: &Self::TotalArray) -> &[__EnumoidValue] {
+        unsafe { #krate::concat_slice(t, <Self as #krate::Enumoid>::SIZE) }
+      }
+      #[inline(always)]
+      fn total_slice_mut(t: &mut Self::TotalArray) -> &mut [__EnumoidValue] {
+        unsafe { #krate::concat_slice_mut(t, <Self as #krate::Enumoid>::SIZE) }
+      }
+      #[inline]
+      fn total_to_partial(t: Self::TotalArray)
+        -> Self::PartialArray {
+        let p = unsafe {
+          ::core::ptr::read(&t as *const _ as *const Self::PartialArray)
+        };
+        ::core::mem::forget(t);
+        p
       }
     }
-  })
+    impl #plane_impl_generics #krate::EnumPackHelper<__EnumoidPlane>
+      for #name #ty_generics #plane_where
+    {
+      type PlaneArray = #plane_array;
+      #[inline(always)]
+      fn plane_slice(p: &Self::PlaneArray) -> &[__EnumoidPlane] {
+        unsafe {
+          #krate::concat_slice(p, <Self as #krate::EnumPackHelper<__EnumoidPlane>>::PACKED_BITS)
+        }
+      }
+      #[inline(always)]
+      fn plane_slice_mut(p: &mut Self::PlaneArray) -> &mut [__EnumoidPlane] {
+        unsafe {
+          #krate::concat_slice_mut(p, <Self as #krate::EnumPackHelper<__EnumoidPlane>>::PACKED_BITS)
+        }
+      }
+      #[inline]
+      fn new_planes<F: FnMut() -> __EnumoidPlane>(mut f: F) -> Self::PlaneArray {
+        #new_planes
+      }
+    }
+    #(#bitset_impls)*
+  }
 }
 
 /// Derive macro which implements the `Enumoid`, `EnumArrayHelper<V>`,
-/// `EnumSetHelper<BitsetWord>`, and `From<EnumIndex<T>>` traits for
-/// a type.
-#[proc_macro_derive(Enumoid, attributes(index_type, bitset_word_types))]
+/// `EnumPackHelper<P>`, `EnumSetHelper<BitsetWord>`, and
+/// `From<EnumIndex<T>>` traits for a type.
+///
+/// Fieldless enums with `#[enumoid(discriminant)]` also implement
+/// `EnumDiscriminant`, `From<Self>` for the discriminant type, and
+/// `TryFrom<_>` from it. The discriminant type is the integer `repr`, or
+/// `isize` if there is none.
+///
+/// The generated code refers to the library as `::enumoid`. If the library is
+/// renamed or re-exported, its path can be given with
+/// `#[enumoid(crate = path::to::enumoid)]`.
+///
+/// Values are ordered by the declaration order of variants unless every
+/// variant has an `#[enumoid(order = N)]` attribute giving its position. The
+/// order of a field's values can be reversed with `#[enumoid(reverse)]`.
+/// Variants marked with `#[enumoid(skip)]` have no index, which must be
+/// allowed with `#[enumoid(partial)]` on the type. `into_word` and the
+/// containers' methods taking keys panic on their values, while `try_`
+/// methods such as `EnumMap::try_get` reject them.
+#[proc_macro_derive(
+  Enumoid,
+  attributes(enumoid, index_type, bitset_word_types)
+)]
 pub fn derive_enumoid(
   input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-  match try_derive_enumoid(input) {
-    Ok(q) => q,
-    Err(e) => {
-      let msg = e.to_string();
-      quote! { compile_error!(#msg); }
-    }
-  }
-  .into()
+  try_derive_enumoid(input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
 }
 
-/// Function-like macro which declares an enum with a contiguous range of
-/// numbered unit variants and a `#[derive(Enumoid)]` attribute.
+/// Function-like macro which declares an enum with numbered unit variants and
+/// a `#[derive(Enumoid)]` attribute.
 ///
 /// ```ignore
 /// generate_enumoid!(Foo, Bar, 1..=3);
@@ -322,16 +1258,51 @@ pub fn derive_enumoid(
 ///
 /// Exclusive ranges (`1..4`) are also supported, but negative indices are not.
 /// Optional leading outer attributes and a visibility may precede the name.
+///
+/// Ranges may have a step (`0..=100 step 10`), and may be followed by more
+/// ranges and numbers. The options `pad = n` and `suffix = Name` pad the
+/// numbers with zeros to `n` digits and append a suffix to the variant names,
+/// `number` adds an inherent `fn number(self) -> u64` returning the number of
+/// a variant, and `repr` adds the narrowest sufficient integer `repr` so that
+/// the derive can convert with casts:
+///
+/// ```ignore
+/// generate_enumoid!(Foo, Bar, 0..=20 step 10, 99, pad = 3, suffix = Hz, number);
+/// ```
+///
+/// declares the variants `Bar000Hz`, `Bar010Hz`, `Bar020Hz` and `Bar099Hz`.
 #[proc_macro]
 pub fn generate_enumoid(
   input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-  match generate::try_generate_enumoid(input) {
-    Ok(q) => q,
-    Err(e) => {
-      let msg = e.to_string();
-      quote! { compile_error!(#msg); }
-    }
-  }
-  .into()
+  generate::try_generate_enumoid(input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// Function-like macro which declares an enum with a unit variant for each
+/// name listed in a data file and a `#[derive(Enumoid)]` attribute, along with
+/// a table of the names.
+///
+/// ```ignore
+/// enumoid_from_file!(pub Sensor, "data/sensors.txt");
+/// ```
+///
+/// The path is relative to `CARGO_MANIFEST_DIR`. Files ending in `.json` hold
+/// an array of strings, files ending in `.csv` have a header row and the names
+/// in their first column, quoted as in RFC 4180 if necessary, and other files
+/// list a name on each line, ignoring blank lines and lines starting with `#`.
+/// Each name must be exactly an identifier, without surrounding whitespace or
+/// an `r#` prefix.
+///
+/// The enum has an associated `NAMES` array, a `name` method, and a
+/// `from_name` function. Optional leading outer attributes, such as a `repr`,
+/// and a visibility may precede the enum's name.
+#[proc_macro]
+pub fn enumoid_from_file(
+  input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+  from_file::try_enumoid_from_file(input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
 }
@@ -0,0 +1,12 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+#[enumoid(partial)]
+enum Foo {
+  #[enumoid(skip)]
+  A,
+  #[enumoid(skip)]
+  B,
+}
+
+fn main() {}
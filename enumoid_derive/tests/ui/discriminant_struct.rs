@@ -0,0 +1,7 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+#[enumoid(discriminant)]
+struct Foo(bool);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+enum Foo {}
+
+fn main() {}
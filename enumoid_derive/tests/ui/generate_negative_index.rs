@@ -0,0 +1,5 @@
+use enumoid::generate_enumoid;
+
+generate_enumoid!(Foo, A, -1..=3);
+
+fn main() {}
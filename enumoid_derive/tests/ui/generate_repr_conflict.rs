@@ -0,0 +1,5 @@
+use enumoid::generate_enumoid;
+
+generate_enumoid!(#[repr(u16)] Foo, A, 1..=5, repr);
+
+fn main() {}
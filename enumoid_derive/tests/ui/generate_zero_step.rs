@@ -0,0 +1,5 @@
+use enumoid::generate_enumoid;
+
+generate_enumoid!(Foo, A, 0..10 step 0);
+
+fn main() {}
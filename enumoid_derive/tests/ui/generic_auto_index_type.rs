@@ -0,0 +1,10 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+#[index_type(auto)]
+enum Slot<T> {
+  Empty,
+  Filled(T),
+}
+
+fn main() {}
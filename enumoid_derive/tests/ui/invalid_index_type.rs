@@ -0,0 +1,9 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+#[index_type(i32)]
+enum Foo {
+  A,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+#[index_type(u64)]
+enum Foo {
+  A(bool, bool),
+  B { inner: bool },
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+enum Foo {
+  #[enumoid(order = 1)]
+  A,
+  #[enumoid(order = 1)]
+  B,
+  #[enumoid(order = 4)]
+  C,
+  D,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+enum Foo {
+  A,
+  #[enumoid(skip)]
+  B(u32),
+}
+
+fn main() {}
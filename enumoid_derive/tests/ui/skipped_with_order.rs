@@ -0,0 +1,12 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+#[enumoid(partial)]
+enum Foo {
+  #[enumoid(order = 0)]
+  A,
+  #[enumoid(skip, order = 1)]
+  B,
+}
+
+fn main() {}
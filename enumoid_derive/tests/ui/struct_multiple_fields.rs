@@ -0,0 +1,6 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+struct Foo(bool, bool);
+
+fn main() {}
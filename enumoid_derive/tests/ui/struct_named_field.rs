@@ -0,0 +1,8 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+struct Foo {
+  inner: bool,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+union Foo {
+  a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+#[enumoid(krate = enumoid)]
+enum Foo {
+  A,
+}
+
+fn main() {}
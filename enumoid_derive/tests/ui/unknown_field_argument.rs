@@ -0,0 +1,8 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+enum Foo {
+  A(#[enumoid(order = 0)] bool),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+enum Foo {
+  A,
+  B(bool, bool),
+}
+
+fn main() {}
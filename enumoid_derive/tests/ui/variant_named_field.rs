@@ -0,0 +1,9 @@
+use enumoid::Enumoid;
+
+#[derive(Enumoid)]
+enum Foo {
+  A,
+  B { inner: bool },
+}
+
+fn main() {}
@@ -0,0 +1,431 @@
+use crate::EnumIndex;
+use crate::base::EnumArrayHelper;
+use crate::base::EnumSetHelper;
+use crate::iter::EnumSliceIter;
+use crate::map::EnumMap;
+use crate::set::EnumSet;
+use crate::sub_base::BagCountTrait;
+use crate::vec::EnumVec;
+use core::fmt;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::iter;
+use core::ops;
+use core::ops::Index;
+
+/// A multiset of enumoid `T`'s members, storing a count of type `N` for each.
+///
+/// The set-like operators are multiset operations: `|` takes the maximum of
+/// each count (union), `&` the minimum (intersection), `+` the sum, and `-`
+/// the difference, saturating at zero.
+pub struct EnumBag<T: EnumArrayHelper<N>, N: BagCountTrait = usize> {
+  counts: EnumMap<T, N>,
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> EnumBag<T, N> {
+  /// Creates a new empty bag.
+  pub fn new() -> Self {
+    EnumBag {
+      counts: EnumMap::new_with(|_| N::ZERO),
+    }
+  }
+
+  /// Returns a map containing the count of every member.
+  #[inline]
+  pub fn as_map(&self) -> &EnumMap<T, N> {
+    &self.counts
+  }
+
+  /// Returns the count of a member index.
+  #[inline]
+  pub fn count_by_index(&self, index: EnumIndex<T>) -> N {
+    self.counts[index]
+  }
+
+  /// Returns the count of a member.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn count(&self, key: T) -> N {
+    self.count_by_index(key.into())
+  }
+
+  /// Sets the count of a member index and returns the old count.
+  #[inline]
+  pub fn set_count_by_index(&mut self, index: EnumIndex<T>, n: N) -> N {
+    self.counts.set_by_index(index, n)
+  }
+
+  /// Sets the count of a member and returns the old count.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn set_count(&mut self, key: T, n: N) -> N {
+    self.set_count_by_index(key.into(), n)
+  }
+
+  /// Adds `n` occurrences of a member index and returns the new count.
+  ///
+  /// # Panics
+  /// Panics if the count overflows `N`.
+  #[inline]
+  pub fn add_by_index(&mut self, index: EnumIndex<T>, n: N) -> N {
+    let count = &mut self.counts[index];
+    *count = count.checked_add(n).expect("EnumBag count overflowed");
+    *count
+  }
+
+  /// Adds `n` occurrences of a member and returns the new count.
+  ///
+  /// # Panics
+  /// Panics if the count overflows `N`.
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn add(&mut self, key: T, n: N) -> N {
+    self.add_by_index(key.into(), n)
+  }
+
+  /// Removes up to `n` occurrences of a member index and returns the number
+  /// actually removed.
+  #[inline]
+  pub fn remove_by_index(&mut self, index: EnumIndex<T>, n: N) -> N {
+    let count = &mut self.counts[index];
+    let removed = n.min(*count);
+    *count = count.saturating_sub(n);
+    removed
+  }
+
+  /// Removes up to `n` occurrences of a member and returns the number
+  /// actually removed.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn remove(&mut self, key: T, n: N) -> N {
+    self.remove_by_index(key.into(), n)
+  }
+
+  /// Removes exactly `n` occurrences of a member index, or returns the
+  /// current count as an error and leaves the bag unchanged if there are
+  /// fewer than `n`.
+  #[inline]
+  pub fn try_remove_by_index(
+    &mut self,
+    index: EnumIndex<T>,
+    n: N,
+  ) -> Result<(), N> {
+    let count = &mut self.counts[index];
+    *count = count.checked_sub(n).ok_or(*count)?;
+    Ok(())
+  }
+
+  /// Removes exactly `n` occurrences of a member, or returns the current
+  /// count as an error and leaves the bag unchanged if there are fewer than
+  /// `n`.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn try_remove(&mut self, key: T, n: N) -> Result<(), N> {
+    self.try_remove_by_index(key.into(), n)
+  }
+
+  /// Clears all the members from the bag.
+  pub fn clear(&mut self) {
+    self.counts.as_slice_mut().fill(N::ZERO);
+  }
+
+  /// Returns true if the bag is empty.
+  pub fn is_empty(&self) -> bool {
+    self.counts.as_slice().iter().all(|&n| n == N::ZERO)
+  }
+
+  /// Returns the total number of occurrences of all the members.
+  ///
+  /// # Panics
+  /// Panics if the total overflows `N`.
+  pub fn total(&self) -> N {
+    self.counts.as_slice().iter().fold(N::ZERO, |acc, &n| {
+      acc.checked_add(n).expect("EnumBag total overflowed")
+    })
+  }
+
+  /// Returns true if every member's count is no greater than in `other`.
+  pub fn is_sub_bag(&self, other: &Self) -> bool {
+    self
+      .counts
+      .as_slice()
+      .iter()
+      .zip(other.counts.as_slice())
+      .all(|(a, b)| a <= b)
+  }
+
+  /// Returns the multiset union, taking the maximum of each count.
+  pub fn union(&self, other: &Self) -> Self {
+    self.zip_with(other, |a, b| a.max(b))
+  }
+
+  /// Returns the multiset intersection, taking the minimum of each count.
+  pub fn intersection(&self, other: &Self) -> Self {
+    self.zip_with(other, |a, b| a.min(b))
+  }
+
+  /// Returns the multiset sum, adding each count.
+  ///
+  /// # Panics
+  /// Panics if a count overflows `N`.
+  pub fn sum(&self, other: &Self) -> Self {
+    self.zip_with(other, |a, b| {
+      a.checked_add(b).expect("EnumBag count overflowed")
+    })
+  }
+
+  /// Returns the multiset difference, subtracting each count and saturating
+  /// at zero.
+  pub fn difference(&self, other: &Self) -> Self {
+    self.zip_with(other, |a, b| a.saturating_sub(b))
+  }
+
+  fn zip_with<F>(&self, other: &Self, mut f: F) -> Self
+  where
+    F: FnMut(N, N) -> N,
+  {
+    EnumBag {
+      counts: EnumMap::new_with(|key| {
+        let index = EnumIndex::from_value(key);
+        f(self.counts[index], other.counts[index])
+      }),
+    }
+  }
+
+  /// Returns an iterator over the members with a non-zero count and their
+  /// counts.
+  #[inline]
+  pub fn iter(&self) -> EnumBagIter<'_, T, N> {
+    EnumBagIter {
+      iter: self.counts.iter(),
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<N> + EnumSetHelper<u8>, N: BagCountTrait>
+  EnumBag<T, N>
+{
+  /// Returns the set of members with a non-zero count.
+  pub fn support(&self) -> EnumSet<T> {
+    self.iter().map(|(key, _)| key).collect()
+  }
+}
+
+impl<T: EnumArrayHelper<N> + EnumArrayHelper<T> + Copy, N: BagCountTrait>
+  EnumBag<T, N>
+{
+  /// Returns the members with a non-zero count, ordered from the highest
+  /// count to the lowest.
+  ///
+  /// Members with equal counts are ordered by their index.
+  pub fn most_common(&self) -> EnumVec<T, T> {
+    let mut keys: EnumVec<T, T> = self.iter().map(|(key, _)| key).collect();
+    keys.as_slice_mut().sort_unstable_by(|&a, &b| {
+      self
+        .count(b)
+        .cmp(&self.count(a))
+        .then_with(|| a.into_word().cmp(&b.into_word()))
+    });
+    keys
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> Clone for EnumBag<T, N> {
+  fn clone(&self) -> Self {
+    EnumBag {
+      counts: self.counts.clone(),
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> Copy for EnumBag<T, N> where
+  T::TotalArray: Copy
+{
+}
+
+impl<T: EnumArrayHelper<N> + Debug, N: BagCountTrait> Debug for EnumBag<T, N> {
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt.debug_map().entries(self.iter()).finish()
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> Default for EnumBag<T, N> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> PartialEq for EnumBag<T, N> {
+  fn eq(&self, other: &Self) -> bool {
+    self.counts == other.counts
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> Eq for EnumBag<T, N> {}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> Hash for EnumBag<T, N> {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+    self.counts.hash(state);
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> From<EnumMap<T, N>>
+  for EnumBag<T, N>
+{
+  fn from(counts: EnumMap<T, N>) -> Self {
+    EnumBag { counts }
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> Index<EnumIndex<T>>
+  for EnumBag<T, N>
+{
+  type Output = N;
+
+  #[inline]
+  fn index(&self, index: EnumIndex<T>) -> &N {
+    &self.counts[index]
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> Index<T> for EnumBag<T, N> {
+  type Output = N;
+
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  fn index(&self, key: T) -> &N {
+    &self.counts[key]
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> ops::BitOr for EnumBag<T, N> {
+  type Output = Self;
+
+  fn bitor(self, rhs: Self) -> Self {
+    self.union(&rhs)
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> ops::BitOrAssign
+  for EnumBag<T, N>
+{
+  fn bitor_assign(&mut self, rhs: Self) {
+    *self = self.union(&rhs);
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> ops::BitAnd for EnumBag<T, N> {
+  type Output = Self;
+
+  fn bitand(self, rhs: Self) -> Self {
+    self.intersection(&rhs)
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> ops::BitAndAssign
+  for EnumBag<T, N>
+{
+  fn bitand_assign(&mut self, rhs: Self) {
+    *self = self.intersection(&rhs);
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> ops::Add for EnumBag<T, N> {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self {
+    self.sum(&rhs)
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> ops::AddAssign for EnumBag<T, N> {
+  fn add_assign(&mut self, rhs: Self) {
+    *self = self.sum(&rhs);
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> ops::Sub for EnumBag<T, N> {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self {
+    self.difference(&rhs)
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> ops::SubAssign for EnumBag<T, N> {
+  fn sub_assign(&mut self, rhs: Self) {
+    *self = self.difference(&rhs);
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> iter::FromIterator<T>
+  for EnumBag<T, N>
+{
+  fn from_iter<I: iter::IntoIterator<Item = T>>(iter: I) -> Self {
+    let mut bag = EnumBag::<T, N>::new();
+    for key in iter {
+      bag.add(key, N::ONE);
+    }
+    bag
+  }
+}
+
+impl<T: EnumArrayHelper<N>, N: BagCountTrait> iter::Extend<T>
+  for EnumBag<T, N>
+{
+  fn extend<I: iter::IntoIterator<Item = T>>(&mut self, iter: I) {
+    for key in iter {
+      self.add(key, N::ONE);
+    }
+  }
+}
+
+impl<'a, T: EnumArrayHelper<N>, N: BagCountTrait> iter::IntoIterator
+  for &'a EnumBag<T, N>
+{
+  type Item = (T, N);
+  type IntoIter = EnumBagIter<'a, T, N>;
+
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+/// An iterator over the members of a bag with a non-zero count.
+pub struct EnumBagIter<'a, T: EnumArrayHelper<N>, N: BagCountTrait> {
+  iter: EnumSliceIter<'a, T, N>,
+}
+
+impl<'a, T: EnumArrayHelper<N>, N: BagCountTrait> Iterator
+  for EnumBagIter<'a, T, N>
+{
+  type Item = (T, N);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    for (key, &n) in self.iter.by_ref() {
+      if n != N::ZERO {
+        return Some((key, n));
+      }
+    }
+    None
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, self.iter.size_hint().1)
+  }
+}
+
+impl<'a, T: EnumArrayHelper<N>, N: BagCountTrait> iter::FusedIterator
+  for EnumBagIter<'a, T, N>
+{
+}
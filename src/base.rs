@@ -1,19 +1,21 @@
+use crate::discriminant::EnumDiscriminant;
 use crate::sub_base::BitsetWordTrait;
 use crate::sub_base::RawSizeWord;
-use std::cmp::Ordering;
-use std::fmt;
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::hash::Hasher;
-use std::iter::Iterator;
-use std::iter::Map;
-use std::mem;
+use core::cmp::Ordering;
+use core::fmt;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::iter::Iterator;
+use core::iter::Map;
+use core::mem;
+use core::ops::Range;
 
 macro_rules! hint_assert {
     ($x:expr, $($arg:tt)*) => {
       debug_assert!($x, $($arg)*);
       if !$x {
-        std::hint::unreachable_unchecked();
+        core::hint::unreachable_unchecked();
       }
     }
 }
@@ -69,6 +71,8 @@ impl<T: Enumoid> EnumSize<T> {
   pub const EMPTY: EnumSize<T> = EnumSize(T::Word::ZERO);
   pub const FULL: EnumSize<T> = EnumSize(T::SIZE_WORD);
 
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn from_last(value: T) -> Self {
     EnumSize(value.into_word().inc())
@@ -87,7 +91,7 @@ impl<T: Enumoid> EnumSize<T> {
     EnumSize(value)
   }
 
-  pub fn from_word(sz: T::Word) -> Option<Self> {
+  pub fn from_index_word(sz: T::Word) -> Option<Self> {
     if sz <= T::SIZE_WORD {
       Some(EnumSize(sz))
     } else {
@@ -104,7 +108,7 @@ impl<T: Enumoid> EnumSize<T> {
   }
 
   #[inline]
-  pub fn into_word(self) -> T::Word {
+  pub fn into_index_word(self) -> T::Word {
     self.0
   }
 
@@ -146,8 +150,9 @@ impl<T: Enumoid> EnumSize<T> {
   ///
   /// # Panics
   /// Panics if the value is beyond the size.
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
-  pub fn next(self, value: T) -> Option<T> {
+  pub fn next_value(self, value: T) -> Option<T> {
     self.next_index(value.into()).map(|i| i.into_value())
   }
 
@@ -169,8 +174,9 @@ impl<T: Enumoid> EnumSize<T> {
   ///
   /// # Panics
   /// Panics if the value is beyond the size.
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
-  pub fn prev(self, value: T) -> Option<T> {
+  pub fn prev_value(self, value: T) -> Option<T> {
     self.prev_index(value.into()).map(|i| i.into_value())
   }
 
@@ -190,8 +196,9 @@ impl<T: Enumoid> EnumSize<T> {
   ///
   /// # Panics
   /// Panics if the value is beyond the size.
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
-  pub fn next_wrapped(self, value: T) -> T {
+  pub fn next_value_wrapped(self, value: T) -> T {
     self.next_index_wrapped(value.into()).into_value()
   }
 
@@ -215,8 +222,9 @@ impl<T: Enumoid> EnumSize<T> {
   ///
   /// # Panics
   /// Panics if the value is beyond the size.
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
-  pub fn prev_wrapped(self, value: T) -> T {
+  pub fn prev_value_wrapped(self, value: T) -> T {
     self.prev_index_wrapped(value.into()).into_value()
   }
 
@@ -225,6 +233,8 @@ impl<T: Enumoid> EnumSize<T> {
     index.0 < self.0
   }
 
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn contains(self, value: T) -> bool {
     value.into_word() < self.0
@@ -251,30 +261,36 @@ impl<T: Enumoid> EnumSize<T> {
   }
 
   #[inline]
-  pub fn iter(self) -> EnumoidIter<T> {
+  pub fn values(self) -> EnumoidIter<T> {
     T::word_range(T::Word::ZERO, self.0)
       .map(|w| unsafe { T::from_word_unchecked(w) })
   }
 
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
-  pub fn iter_until(self, until: T) -> EnumoidIter<T> {
+  pub fn values_until(self, until: T) -> EnumoidIter<T> {
     T::word_range(T::Word::ZERO, self.0.min(until.into_word().inc()))
       .map(|w| unsafe { T::from_word_unchecked(w) })
   }
 
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
-  pub fn iter_from(self, from: T) -> EnumoidIter<T> {
+  pub fn values_from(self, from: T) -> EnumoidIter<T> {
     T::word_range(from.into_word(), self.0)
       .map(|w| unsafe { T::from_word_unchecked(w) })
   }
 
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
-  pub fn iter_from_until(self, from: T, until: T) -> EnumoidIter<T> {
+  pub fn values_from_until(self, from: T, until: T) -> EnumoidIter<T> {
     let w = until.into_word().inc();
     if w < self.0 {
-      unsafe { EnumSize::from_word_unchecked(w) }.iter_from(from)
+      unsafe { EnumSize::from_word_unchecked(w) }.values_from(from)
     } else {
-      self.iter_from(from)
+      self.values_from(from)
     }
   }
 }
@@ -344,10 +360,22 @@ impl<T: Enumoid> EnumIndex<T> {
     }
   }
 
+  /// Returns the index of a value.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   pub fn from_value(value: T) -> Self {
     unsafe { Self::from_word_unchecked(value.into_word()) }
   }
 
+  /// Returns the index of a value, or `None` if the value is skipped.
+  #[inline]
+  pub fn try_from_value(value: T) -> Option<Self> {
+    value
+      .try_into_word()
+      .map(|word| unsafe { Self::from_word_unchecked(word) })
+  }
+
   #[inline]
   pub fn into_value(self) -> T {
     unsafe { T::from_word_unchecked(self.0) }
@@ -397,6 +425,8 @@ impl<T: Enumoid> EnumIndex<T> {
 }
 
 impl<T: Enumoid> From<T> for EnumIndex<T> {
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   fn from(value: T) -> Self {
     EnumIndex(value.into_word())
@@ -411,12 +441,50 @@ pub trait Enumoid: Sized {
   const SIZE: usize;
   const FIRST: Self;
   const LAST: Self;
+
+  /// Returns the index word of a value.
+  ///
+  /// # Panics
+  /// Panics if the value's variant is skipped with `#[enumoid(skip)]`. The
+  /// methods of this crate which take Enumoid values by value convert them
+  /// with this, so they panic likewise. `try_into_word`,
+  /// `EnumIndex::try_from_value`, and the containers' `try_` methods reject
+  /// such values instead.
   fn into_word(self) -> Self::Word;
 
+  /// Returns the index word of a value, or `None` if the value's variant is
+  /// skipped with `#[enumoid(skip)]`.
+  #[inline]
+  fn try_into_word(self) -> Option<Self::Word> {
+    Some(self.into_word())
+  }
+
+  /// Returns the discriminant of a fieldless enum value. Unlike the index
+  /// returned by `into_word`, this respects explicit discriminants.
+  #[inline]
+  fn discriminant(self) -> Self::Repr
+  where
+    Self: EnumDiscriminant,
+  {
+    self.into_discriminant()
+  }
+
   #[doc(hidden)]
   type WordRange: Iterator<Item = Self::Word>;
   #[doc(hidden)]
   const SIZE_WORD: Self::Word;
+  /// The index word of `LAST`.
+  #[doc(hidden)]
+  const LAST_WORD: Self::Word;
+  /// The index word of `EnumSize<Self>`, which can represent `SIZE + 1`.
+  #[doc(hidden)]
+  type SizeWord: RawSizeWord;
+  /// `SIZE` as a `SizeWord`, the index word of `EnumSize::<Self>::FULL`.
+  #[doc(hidden)]
+  const SIZES_LAST_WORD: Self::SizeWord;
+  /// `SIZE + 1` as a `SizeWord`, the number of sizes.
+  #[doc(hidden)]
+  const SIZES_SIZE_WORD: Self::SizeWord;
   /// # Safety
   /// The input word must be less than SIZE.
   #[doc(hidden)]
@@ -435,42 +503,42 @@ pub trait Enumoid: Sized {
 
   #[inline]
   fn next(self) -> Option<Self> {
-    EnumSize::FULL.next(self)
+    EnumSize::FULL.next_value(self)
   }
 
   #[inline]
   fn prev(self) -> Option<Self> {
-    EnumSize::FULL.prev(self)
+    EnumSize::FULL.prev_value(self)
   }
 
   #[inline]
   fn next_wrapped(self) -> Self {
-    EnumSize::FULL.next_wrapped(self)
+    EnumSize::FULL.next_value_wrapped(self)
   }
 
   #[inline]
   fn prev_wrapped(self) -> Self {
-    EnumSize::FULL.prev_wrapped(self)
+    EnumSize::FULL.prev_value_wrapped(self)
   }
 
   #[inline]
   fn iter() -> EnumoidIter<Self> {
-    EnumSize::FULL.iter()
+    EnumSize::FULL.values()
   }
 
   #[inline]
   fn iter_until(until: Self) -> EnumoidIter<Self> {
-    EnumSize::from_last(until).iter()
+    EnumSize::from_last(until).values()
   }
 
   #[inline]
   fn iter_from(from: Self) -> EnumoidIter<Self> {
-    EnumSize::FULL.iter_from(from)
+    EnumSize::FULL.values_from(from)
   }
 
   #[inline]
   fn iter_from_until(from: Self, until: Self) -> EnumoidIter<Self> {
-    EnumSize::from_last(until).iter_from(from)
+    EnumSize::from_last(until).values_from(from)
   }
 }
 
@@ -528,3 +596,316 @@ pub trait EnumSetHelper<BitsetWord: BitsetWordTrait>: Enumoid {
   #[doc(hidden)]
   fn slice_bitset_mut(arr: &mut Self::BitsetArray) -> &mut [Self::BitsetWord];
 }
+
+/// Workaround for const generics not supporting associated consts yet.
+///
+/// Provides an array with one `Plane` for each bit needed to store the index
+/// of a member. All the members are hidden. Impls should only be defined via
+/// the `Enumoid` derive macro.
+pub trait EnumPackHelper<Plane: Sized>: Enumoid {
+  #[doc(hidden)]
+  const PACKED_BITS: usize =
+    (usize::BITS - (Self::SIZE - 1).leading_zeros()) as usize;
+  #[doc(hidden)]
+  type PlaneArray: Sized;
+
+  #[doc(hidden)]
+  fn plane_slice(p: &Self::PlaneArray) -> &[Plane];
+  #[doc(hidden)]
+  fn plane_slice_mut(p: &mut Self::PlaneArray) -> &mut [Plane];
+  #[doc(hidden)]
+  fn new_planes<F: FnMut() -> Plane>(f: F) -> Self::PlaneArray;
+}
+
+/// The indices of an Enumoid are themselves an Enumoid, with the same index
+/// words.
+impl<T: Enumoid> Enumoid for EnumIndex<T> {
+  type Word = T::Word;
+  const SIZE: usize = T::SIZE;
+  const FIRST: Self = EnumIndex(T::Word::ZERO);
+  const LAST: Self = EnumIndex(T::LAST_WORD);
+
+  #[inline]
+  fn into_word(self) -> Self::Word {
+    self.0
+  }
+
+  type WordRange = T::WordRange;
+  const SIZE_WORD: Self::Word = T::SIZE_WORD;
+  const LAST_WORD: Self::Word = T::LAST_WORD;
+  type SizeWord = T::SizeWord;
+  const SIZES_LAST_WORD: Self::SizeWord = T::SIZES_LAST_WORD;
+  const SIZES_SIZE_WORD: Self::SizeWord = T::SIZES_SIZE_WORD;
+
+  #[inline]
+  unsafe fn from_word_unchecked(value: Self::Word) -> Self {
+    unsafe { EnumIndex::from_word_unchecked(value) }
+  }
+
+  #[inline]
+  fn word_range(base: Self::Word, sz: Self::Word) -> Self::WordRange {
+    T::word_range(base, sz)
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V> EnumArrayHelper<V> for EnumIndex<T> {
+  type PartialArray = T::PartialArray;
+  type TotalArray = T::TotalArray;
+
+  #[inline(always)]
+  fn partial_slice(p: &Self::PartialArray) -> &[mem::MaybeUninit<V>] {
+    T::partial_slice(p)
+  }
+
+  #[inline(always)]
+  fn partial_slice_mut(
+    p: &mut Self::PartialArray,
+  ) -> &mut [mem::MaybeUninit<V>] {
+    T::partial_slice_mut(p)
+  }
+
+  #[inline]
+  unsafe fn partial_to_total(p: Self::PartialArray) -> Self::TotalArray {
+    unsafe { T::partial_to_total(p) }
+  }
+
+  #[inline(always)]
+  fn total_slice(t: &Self::TotalArray) -> &[V] {
+    T::total_slice(t)
+  }
+
+  #[inline(always)]
+  fn total_slice_mut(t: &mut Self::TotalArray) -> &mut [V] {
+    T::total_slice_mut(t)
+  }
+
+  #[inline]
+  fn total_to_partial(t: Self::TotalArray) -> Self::PartialArray {
+    T::total_to_partial(t)
+  }
+}
+
+impl<T: EnumSetHelper<W>, W: BitsetWordTrait> EnumSetHelper<W>
+  for EnumIndex<T>
+{
+  type BitsetWord = T::BitsetWord;
+  type BitsetArray = T::BitsetArray;
+  const BITSET_WORD_BITS: usize = T::BITSET_WORD_BITS;
+  const BITSET_WORDS: usize = T::BITSET_WORDS;
+  const DEFAULT_BITSET: Self::BitsetArray = T::DEFAULT_BITSET;
+
+  #[inline(always)]
+  fn slice_bitset(arr: &Self::BitsetArray) -> &[Self::BitsetWord] {
+    T::slice_bitset(arr)
+  }
+
+  #[inline(always)]
+  fn slice_bitset_mut(arr: &mut Self::BitsetArray) -> &mut [Self::BitsetWord] {
+    T::slice_bitset_mut(arr)
+  }
+}
+
+impl<T: EnumPackHelper<P>, P> EnumPackHelper<P> for EnumIndex<T> {
+  const PACKED_BITS: usize = T::PACKED_BITS;
+  type PlaneArray = T::PlaneArray;
+
+  #[inline(always)]
+  fn plane_slice(p: &Self::PlaneArray) -> &[P] {
+    T::plane_slice(p)
+  }
+
+  #[inline(always)]
+  fn plane_slice_mut(p: &mut Self::PlaneArray) -> &mut [P] {
+    T::plane_slice_mut(p)
+  }
+
+  #[inline]
+  fn new_planes<F: FnMut() -> P>(f: F) -> Self::PlaneArray {
+    T::new_planes(f)
+  }
+}
+
+/// The sizes of an Enumoid are themselves an Enumoid. There is one more size
+/// than there are values, so the index words are `T::SizeWord`, which is only
+/// wider than `T::Word` if `T::SIZE + 1` needs it. The sizes of sizes use
+/// `usize` index words.
+impl<T: Enumoid> Enumoid for EnumSize<T> {
+  type Word = T::SizeWord;
+  const SIZE: usize = T::SIZE + 1;
+  const FIRST: Self = EnumSize::EMPTY;
+  const LAST: Self = EnumSize::FULL;
+
+  #[inline]
+  fn into_word(self) -> Self::Word {
+    Self::Word::from_usize_unchecked(self.into_usize())
+  }
+
+  type WordRange = Map<Range<usize>, fn(usize) -> Self::Word>;
+  const SIZE_WORD: Self::Word = T::SIZES_SIZE_WORD;
+  const LAST_WORD: Self::Word = T::SIZES_LAST_WORD;
+  type SizeWord = usize;
+  const SIZES_LAST_WORD: usize = T::SIZE + 1;
+  const SIZES_SIZE_WORD: usize = T::SIZE + 2;
+
+  #[inline]
+  unsafe fn from_word_unchecked(value: Self::Word) -> Self {
+    unsafe {
+      EnumSize::from_word_unchecked(T::Word::from_usize_unchecked(value.as_()))
+    }
+  }
+
+  #[inline]
+  fn word_range(base: Self::Word, sz: Self::Word) -> Self::WordRange {
+    (base.as_()..sz.as_()).map(Self::Word::from_usize_unchecked)
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V> EnumArrayHelper<V> for EnumSize<T> {
+  type PartialArray = ArrayConcat<T::PartialArray, [mem::MaybeUninit<V>; 1]>;
+  type TotalArray = ArrayConcat<T::TotalArray, [V; 1]>;
+
+  #[inline(always)]
+  fn partial_slice(p: &Self::PartialArray) -> &[mem::MaybeUninit<V>] {
+    unsafe { concat_slice(p, Self::SIZE) }
+  }
+
+  #[inline(always)]
+  fn partial_slice_mut(
+    p: &mut Self::PartialArray,
+  ) -> &mut [mem::MaybeUninit<V>] {
+    unsafe { concat_slice_mut(p, Self::SIZE) }
+  }
+
+  #[inline]
+  unsafe fn partial_to_total(p: Self::PartialArray) -> Self::TotalArray {
+    let ArrayConcat(init, [last]) = p;
+    unsafe { ArrayConcat(T::partial_to_total(init), [last.assume_init()]) }
+  }
+
+  #[inline(always)]
+  fn total_slice(t: &Self::TotalArray) -> &[V] {
+    unsafe { concat_slice(t, Self::SIZE) }
+  }
+
+  #[inline(always)]
+  fn total_slice_mut(t: &mut Self::TotalArray) -> &mut [V] {
+    unsafe { concat_slice_mut(t, Self::SIZE) }
+  }
+
+  #[inline]
+  fn total_to_partial(t: Self::TotalArray) -> Self::PartialArray {
+    let ArrayConcat(init, [last]) = t;
+    ArrayConcat(T::total_to_partial(init), [mem::MaybeUninit::new(last)])
+  }
+}
+
+impl<T: EnumSetHelper<W>, W: BitsetWordTrait> EnumSetHelper<W> for EnumSize<T> {
+  type BitsetWord = T::BitsetWord;
+  type BitsetArray = ArrayConcat<T::BitsetArray, [T::BitsetWord; 1]>;
+  const BITSET_WORD_BITS: usize = T::BITSET_WORD_BITS;
+  const DEFAULT_BITSET: Self::BitsetArray =
+    ArrayConcat(T::DEFAULT_BITSET, [T::BitsetWord::ZERO]);
+
+  #[inline(always)]
+  fn slice_bitset(arr: &Self::BitsetArray) -> &[Self::BitsetWord] {
+    unsafe { concat_slice(arr, Self::BITSET_WORDS) }
+  }
+
+  #[inline(always)]
+  fn slice_bitset_mut(arr: &mut Self::BitsetArray) -> &mut [Self::BitsetWord] {
+    unsafe { concat_slice_mut(arr, Self::BITSET_WORDS) }
+  }
+}
+
+impl<T: EnumPackHelper<P>, P> EnumPackHelper<P> for EnumSize<T> {
+  type PlaneArray = ArrayConcat<T::PlaneArray, [P; 1]>;
+
+  #[inline(always)]
+  fn plane_slice(p: &Self::PlaneArray) -> &[P] {
+    unsafe { concat_slice(p, Self::PACKED_BITS) }
+  }
+
+  #[inline(always)]
+  fn plane_slice_mut(p: &mut Self::PlaneArray) -> &mut [P] {
+    unsafe { concat_slice_mut(p, Self::PACKED_BITS) }
+  }
+
+  #[inline]
+  fn new_planes<F: FnMut() -> P>(mut f: F) -> Self::PlaneArray {
+    ArrayConcat(T::new_planes(&mut f), [f()])
+  }
+}
+
+/// Workaround for const generics not supporting associated consts yet.
+///
+/// Selects the narrowest index word type which can represent a given number
+/// of members, where `TIER` is computed by `auto_word_tier`. Used by the
+/// `Enumoid` derive macro for `#[index_type(auto)]`.
+#[doc(hidden)]
+pub struct AutoWord<const TIER: u8>;
+
+#[doc(hidden)]
+pub trait AutoWordHelper {
+  type Word: RawSizeWord;
+}
+
+impl AutoWordHelper for AutoWord<0> {
+  type Word = u8;
+}
+
+impl AutoWordHelper for AutoWord<1> {
+  type Word = u16;
+}
+
+impl AutoWordHelper for AutoWord<2> {
+  type Word = u32;
+}
+
+impl AutoWordHelper for AutoWord<3> {
+  type Word = usize;
+}
+
+#[doc(hidden)]
+pub const fn auto_word_tier(size: usize) -> u8 {
+  if size <= u8::MAX as usize {
+    0
+  } else if size <= u16::MAX as usize {
+    1
+  } else if size <= u32::MAX as usize {
+    2
+  } else {
+    3
+  }
+}
+
+/// Workaround for const generics not supporting generic array lengths.
+///
+/// Concatenates two arrays with the same element type. The `Enumoid` derive
+/// macro uses nested `ArrayConcat`s in place of arrays for generic types, and
+/// `repr(C)` lays out their elements contiguously.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct ArrayConcat<A, B>(pub A, pub B);
+
+/// Returns a slice over the first `len` elements of an array or nested
+/// `ArrayConcat`.
+///
+/// # Safety
+/// `A` must consist of at least `len` contiguous elements of type `V`.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn concat_slice<A, V>(a: &A, len: usize) -> &[V] {
+  unsafe { core::slice::from_raw_parts(a as *const A as *const V, len) }
+}
+
+/// Returns a mutable slice over the first `len` elements of an array or nested
+/// `ArrayConcat`.
+///
+/// # Safety
+/// `A` must consist of at least `len` contiguous elements of type `V`.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn concat_slice_mut<A, V>(a: &mut A, len: usize) -> &mut [V] {
+  unsafe { core::slice::from_raw_parts_mut(a as *mut A as *mut V, len) }
+}
@@ -0,0 +1,289 @@
+use crate::EnumIndex;
+use crate::base::EnumArrayHelper;
+use crate::base::EnumSetHelper;
+use crate::opt_map::EnumOptionMap;
+use crate::opt_map::EnumOptionMapIter;
+use core::fmt;
+use core::fmt::Debug;
+use core::iter;
+
+/// A partial one-to-one mapping between the values of enumoids `A` and `B`.
+///
+/// The mapping is stored in both directions as a pair of `EnumOptionMap`s so
+/// that it can be queried from either side.
+pub struct EnumBiMap<
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> {
+  left: EnumOptionMap<A, B>,
+  right: EnumOptionMap<B, A>,
+}
+
+impl<
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> EnumBiMap<A, B>
+{
+  /// Creates a new empty map.
+  pub fn new() -> Self {
+    EnumBiMap {
+      left: EnumOptionMap::new(),
+      right: EnumOptionMap::new(),
+    }
+  }
+
+  /// Returns the partial map from left values to right values.
+  #[inline]
+  pub fn as_left(&self) -> &EnumOptionMap<A, B> {
+    &self.left
+  }
+
+  /// Returns the partial map from right values to left values.
+  #[inline]
+  pub fn as_right(&self) -> &EnumOptionMap<B, A> {
+    &self.right
+  }
+
+  /// Returns the right value paired with a given left value.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn get_by_left(&self, a: A) -> Option<B> {
+    self.left.get(a).copied()
+  }
+
+  /// Returns the left value paired with a given right value.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn get_by_right(&self, b: B) -> Option<A> {
+    self.right.get(b).copied()
+  }
+
+  /// Returns true if the left value is paired.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn contains_left(&self, a: A) -> bool {
+    self.left.contains(a)
+  }
+
+  /// Returns true if the right value is paired.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn contains_right(&self, b: B) -> bool {
+    self.right.contains(b)
+  }
+
+  /// Pairs two values and returns any pairs which were displaced.
+  ///
+  /// The first element of the result is the old pair containing `a` and the
+  /// second is the old pair containing `b`. If `a` and `b` were already
+  /// paired with each other, that pair is returned in the first element.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[allow(clippy::type_complexity)]
+  pub fn insert(&mut self, a: A, b: B) -> (Option<(A, B)>, Option<(A, B)>) {
+    let by_left = self.remove_by_left(a);
+    let by_right = self.remove_by_right(b);
+    self.left.insert(a, b);
+    self.right.insert(b, a);
+    self.debug_check_invariants();
+    (by_left, by_right)
+  }
+
+  /// Removes the pair containing a given left value and returns it.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  pub fn remove_by_left(&mut self, a: A) -> Option<(A, B)> {
+    let b = self.left.remove(a)?;
+    self.right.remove(b);
+    self.debug_check_invariants();
+    Some((a, b))
+  }
+
+  /// Removes the pair containing a given right value and returns it.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  pub fn remove_by_right(&mut self, b: B) -> Option<(A, B)> {
+    let a = self.right.remove(b)?;
+    self.left.remove(a);
+    self.debug_check_invariants();
+    Some((a, b))
+  }
+
+  /// Clears all the pairs from the map.
+  pub fn clear(&mut self) {
+    self.left.clear();
+    self.right.clear();
+  }
+
+  /// Returns true if the map is empty.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.left.is_empty()
+  }
+
+  /// Returns the number of pairs in the map.
+  #[inline]
+  pub fn count(&self) -> usize {
+    self.left.count()
+  }
+
+  /// Returns an iterator over the pairs in the order of their left values.
+  #[inline]
+  pub fn iter(&self) -> EnumBiMapIter<'_, A, B> {
+    EnumBiMapIter {
+      iter: self.left.iter(),
+    }
+  }
+
+  fn debug_check_invariants(&self) {
+    if cfg!(debug_assertions) {
+      assert_eq!(self.left.count(), self.right.count());
+      for (a, &b) in self.left.iter() {
+        assert!(
+          self.right.get(b).map(|&x| EnumIndex::from_value(x))
+            == Some(EnumIndex::from_value(a)),
+          "EnumBiMap directions are inconsistent"
+        );
+      }
+    }
+  }
+}
+
+impl<
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy + Debug,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy + Debug,
+> Debug for EnumBiMap<A, B>
+{
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt.debug_map().entries(self.iter()).finish()
+  }
+}
+
+impl<
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> Default for EnumBiMap<A, B>
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> Clone for EnumBiMap<A, B>
+{
+  fn clone(&self) -> Self {
+    self.iter().collect()
+  }
+}
+
+impl<
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> PartialEq for EnumBiMap<A, B>
+{
+  fn eq(&self, other: &Self) -> bool {
+    self.count() == other.count()
+      && self.iter().all(|(a, b)| {
+        other.get_by_left(a).map(EnumIndex::from_value)
+          == Some(EnumIndex::from_value(b))
+      })
+  }
+}
+
+impl<
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> Eq for EnumBiMap<A, B>
+{
+}
+
+impl<
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> iter::FromIterator<(A, B)> for EnumBiMap<A, B>
+{
+  fn from_iter<I: iter::IntoIterator<Item = (A, B)>>(iter: I) -> Self {
+    let mut map = EnumBiMap::new();
+    for (a, b) in iter {
+      map.insert(a, b);
+    }
+    map
+  }
+}
+
+impl<
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> iter::Extend<(A, B)> for EnumBiMap<A, B>
+{
+  fn extend<I: iter::IntoIterator<Item = (A, B)>>(&mut self, iter: I) {
+    for (a, b) in iter {
+      self.insert(a, b);
+    }
+  }
+}
+
+impl<
+  'a,
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> iter::IntoIterator for &'a EnumBiMap<A, B>
+{
+  type Item = (A, B);
+  type IntoIter = EnumBiMapIter<'a, A, B>;
+
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+/// An iterator over the pairs of a bijective map.
+pub struct EnumBiMapIter<
+  'a,
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> {
+  iter: EnumOptionMapIter<'a, A, B, u8>,
+}
+
+impl<
+  'a,
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> Iterator for EnumBiMapIter<'a, A, B>
+{
+  type Item = (A, B);
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.iter.next().map(|(a, &b)| (a, b))
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.iter.size_hint()
+  }
+}
+
+impl<
+  'a,
+  A: EnumArrayHelper<B> + EnumSetHelper<u8> + Copy,
+  B: EnumArrayHelper<A> + EnumSetHelper<u8> + Copy,
+> iter::FusedIterator for EnumBiMapIter<'a, A, B>
+{
+}
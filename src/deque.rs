@@ -0,0 +1,399 @@
+use crate::EnumIndex;
+use crate::base::EnumArrayHelper;
+use crate::base::EnumSize;
+use crate::sub_base::RawSizeWord;
+use core::fmt;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::iter;
+use core::marker;
+use core::mem;
+use core::slice;
+
+/// A double-ended queue of values `V` with a capacity of enumoid `T`'s size.
+///
+/// The elements are stored in a ring buffer, so they occupy up to two
+/// contiguous runs of the underlying array. Positions in the queue are
+/// indexed by `T` from the front.
+pub struct EnumDeque<T: EnumArrayHelper<V>, V> {
+  head: T::Word,
+  len: T::Word,
+  data: T::PartialArray,
+}
+
+/// Reinterprets a slice of initialised cells as a slice of values.
+///
+/// # Safety
+/// Every cell in the slice must be initialised.
+unsafe fn assume_init_slice<V>(cells: &[mem::MaybeUninit<V>]) -> &[V] {
+  unsafe { &*(cells as *const [mem::MaybeUninit<V>] as *const [V]) }
+}
+
+/// Reinterprets a mutable slice of initialised cells as a slice of values.
+///
+/// # Safety
+/// Every cell in the slice must be initialised.
+unsafe fn assume_init_slice_mut<V>(
+  cells: &mut [mem::MaybeUninit<V>],
+) -> &mut [V] {
+  unsafe { &mut *(cells as *mut [mem::MaybeUninit<V>] as *mut [V]) }
+}
+
+impl<T: EnumArrayHelper<V>, V> EnumDeque<T, V> {
+  /// Creates a new queue with no elements.
+  pub fn new() -> Self {
+    EnumDeque {
+      head: T::Word::ZERO,
+      len: T::Word::ZERO,
+      data: T::new_partial(),
+    }
+  }
+
+  /// Returns the position in the array of a position in the queue.
+  #[inline]
+  fn physical(&self, offset: usize) -> usize {
+    let position = self.head.as_() + offset;
+    if position >= T::SIZE {
+      position - T::SIZE
+    } else {
+      position
+    }
+  }
+
+  /// Returns the array ranges of the front and back runs of elements.
+  #[inline]
+  fn ranges(&self) -> (core::ops::Range<usize>, core::ops::Range<usize>) {
+    let head = self.head.as_();
+    let end = head + self.len.as_();
+    if end > T::SIZE {
+      (head..T::SIZE, 0..end - T::SIZE)
+    } else {
+      (head..end, 0..0)
+    }
+  }
+
+  /// Returns a pair of slices containing the elements in order.
+  ///
+  /// The second slice is only non-empty if the elements wrap around the end
+  /// of the underlying array.
+  pub fn as_slices(&self) -> (&[V], &[V]) {
+    let (front, back) = self.ranges();
+    let cells = T::partial_slice(&self.data);
+    unsafe {
+      (
+        assume_init_slice(&cells[front]),
+        assume_init_slice(&cells[back]),
+      )
+    }
+  }
+
+  /// Returns a pair of mutable slices containing the elements in order.
+  pub fn as_slices_mut(&mut self) -> (&mut [V], &mut [V]) {
+    let (front, back) = self.ranges();
+    let cells = T::partial_slice_mut(&mut self.data);
+    let (start, end) = cells.split_at_mut(front.start);
+    unsafe {
+      (
+        assume_init_slice_mut(&mut end[..front.len()]),
+        assume_init_slice_mut(&mut start[back]),
+      )
+    }
+  }
+
+  /// Rearranges the elements so that they are contiguous and returns them as
+  /// a single mutable slice.
+  pub fn make_contiguous(&mut self) -> &mut [V] {
+    let head = self.head.as_();
+    let cells = T::partial_slice_mut(&mut self.data);
+    if head + self.len.as_() > T::SIZE {
+      cells.rotate_left(head);
+      self.head = T::Word::ZERO;
+    }
+    let head = self.head.as_();
+    unsafe { assume_init_slice_mut(&mut cells[head..head + self.len.as_()]) }
+  }
+
+  /// Returns a reference to the element at a given index from the front,
+  /// or `None` if the index is beyond the end of the queue.
+  #[inline]
+  pub fn get_by_index(&self, index: EnumIndex<T>) -> Option<&V> {
+    let offset = index.into_usize();
+    if offset < self.len.as_() {
+      let position = self.physical(offset);
+      Some(unsafe { T::partial_slice(&self.data)[position].assume_init_ref() })
+    } else {
+      None
+    }
+  }
+
+  /// Returns a reference to the element at a given key from the front,
+  /// or `None` if the key is beyond the end of the queue.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn get(&self, key: T) -> Option<&V> {
+    self.get_by_index(key.into())
+  }
+
+  /// Returns a mutable reference to the element at a given index from the
+  /// front, or `None` if the index is beyond the end of the queue.
+  #[inline]
+  pub fn get_by_index_mut(&mut self, index: EnumIndex<T>) -> Option<&mut V> {
+    let offset = index.into_usize();
+    if offset < self.len.as_() {
+      let position = self.physical(offset);
+      Some(unsafe {
+        T::partial_slice_mut(&mut self.data)[position].assume_init_mut()
+      })
+    } else {
+      None
+    }
+  }
+
+  /// Returns a mutable reference to the element at a given key from the
+  /// front, or `None` if the key is beyond the end of the queue.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn get_mut(&mut self, key: T) -> Option<&mut V> {
+    self.get_by_index_mut(key.into())
+  }
+
+  /// Returns a reference to the front element, or `None` if the queue is
+  /// empty.
+  #[inline]
+  pub fn front(&self) -> Option<&V> {
+    self.get_by_index(EnumIndex::from_value(T::FIRST))
+  }
+
+  /// Returns a mutable reference to the front element, or `None` if the
+  /// queue is empty.
+  #[inline]
+  pub fn front_mut(&mut self) -> Option<&mut V> {
+    self.get_by_index_mut(EnumIndex::from_value(T::FIRST))
+  }
+
+  /// Returns a reference to the back element, or `None` if the queue is
+  /// empty.
+  #[inline]
+  pub fn back(&self) -> Option<&V> {
+    self
+      .size()
+      .into_last_index()
+      .and_then(|i| self.get_by_index(i))
+  }
+
+  /// Returns a mutable reference to the back element, or `None` if the queue
+  /// is empty.
+  #[inline]
+  pub fn back_mut(&mut self) -> Option<&mut V> {
+    self
+      .size()
+      .into_last_index()
+      .and_then(|i| self.get_by_index_mut(i))
+  }
+
+  /// Returns true if the queue is empty.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len == T::Word::ZERO
+  }
+
+  /// Returns true if the queue is fully populated.
+  #[inline]
+  pub fn is_full(&self) -> bool {
+    self.len == T::SIZE_WORD
+  }
+
+  /// Returns the size of the queue.
+  #[inline]
+  pub fn size(&self) -> EnumSize<T> {
+    unsafe { EnumSize::from_word_unchecked(self.len) }
+  }
+
+  /// Adds an element to the back of the queue.
+  pub fn try_push_back(&mut self, value: V) -> Result<(), V> {
+    if self.is_full() {
+      return Err(value);
+    }
+    let position = self.physical(self.len.as_());
+    T::partial_slice_mut(&mut self.data)[position].write(value);
+    self.len = self.len.inc();
+    Ok(())
+  }
+
+  /// Adds an element to the front of the queue.
+  pub fn try_push_front(&mut self, value: V) -> Result<(), V> {
+    if self.is_full() {
+      return Err(value);
+    }
+    let head = self.head.as_();
+    let position = if head == 0 { T::SIZE - 1 } else { head - 1 };
+    T::partial_slice_mut(&mut self.data)[position].write(value);
+    self.head = T::Word::from_usize_unchecked(position);
+    self.len = self.len.inc();
+    Ok(())
+  }
+
+  /// Adds an element to the back of the queue, removing and returning the
+  /// front element if the queue is full.
+  pub fn push_back_overwriting(&mut self, value: V) -> Option<V> {
+    let evicted = if self.is_full() {
+      self.pop_front()
+    } else {
+      None
+    };
+    // There is always room after evicting from a full queue.
+    let _ = self.try_push_back(value);
+    evicted
+  }
+
+  /// Removes an element from the front of the queue and returns it,
+  /// or `None` if the queue is empty.
+  pub fn pop_front(&mut self) -> Option<V> {
+    if self.is_empty() {
+      return None;
+    }
+    let head = self.head.as_();
+    let value =
+      unsafe { T::partial_slice(&self.data)[head].assume_init_read() };
+    self.head = T::Word::from_usize_unchecked(self.physical(1));
+    self.len = self.len.dec();
+    Some(value)
+  }
+
+  /// Removes an element from the back of the queue and returns it,
+  /// or `None` if the queue is empty.
+  pub fn pop_back(&mut self) -> Option<V> {
+    if self.is_empty() {
+      return None;
+    }
+    self.len = self.len.dec();
+    let position = self.physical(self.len.as_());
+    Some(unsafe { T::partial_slice(&self.data)[position].assume_init_read() })
+  }
+
+  /// Clears all the elements from the queue.
+  pub fn clear(&mut self) {
+    let (front, back) = self.ranges();
+    // Reset first so that a panicking destructor cannot cause a double drop.
+    self.head = T::Word::ZERO;
+    self.len = T::Word::ZERO;
+    let (start, end) =
+      T::partial_slice_mut(&mut self.data).split_at_mut(front.start);
+    for cell in end[..front.len()].iter_mut().chain(start[back].iter_mut()) {
+      unsafe { cell.assume_init_drop() };
+    }
+  }
+
+  /// Returns an iterator over the keys and elements, from front to back.
+  #[inline]
+  pub fn iter(&self) -> EnumDequeIter<'_, T, V> {
+    let (front, back) = self.as_slices();
+    EnumDequeIter {
+      _phantom: Default::default(),
+      word: T::Word::ZERO,
+      iter: front.iter().chain(back.iter()),
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<V> + Debug, V: Debug> Debug for EnumDeque<T, V> {
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt.debug_map().entries(self.iter()).finish()
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V> Default for EnumDeque<T, V> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V> Drop for EnumDeque<T, V> {
+  fn drop(&mut self) {
+    self.clear()
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Clone> Clone for EnumDeque<T, V> {
+  fn clone(&self) -> Self {
+    self.iter().map(|(_, value)| value.clone()).collect()
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: PartialEq> PartialEq for EnumDeque<T, V> {
+  fn eq(&self, other: &Self) -> bool {
+    self.size() == other.size()
+      && self.iter().zip(other.iter()).all(|((_, a), (_, b))| a == b)
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Eq> Eq for EnumDeque<T, V> {}
+
+impl<T: EnumArrayHelper<V>, V: Hash> Hash for EnumDeque<T, V> {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+    self.size().hash(state);
+    for (_, value) in self.iter() {
+      value.hash(state);
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V> iter::FromIterator<V> for EnumDeque<T, V> {
+  fn from_iter<I: iter::IntoIterator<Item = V>>(iter: I) -> Self {
+    let mut c = EnumDeque::<T, V>::new();
+    c.extend(iter);
+    c
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V> iter::Extend<V> for EnumDeque<T, V> {
+  fn extend<I: iter::IntoIterator<Item = V>>(&mut self, iter: I) {
+    for i in iter {
+      if self.try_push_back(i).is_err() {
+        break;
+      }
+    }
+  }
+}
+
+impl<'a, T: EnumArrayHelper<V>, V> iter::IntoIterator for &'a EnumDeque<T, V> {
+  type Item = (T, &'a V);
+  type IntoIter = EnumDequeIter<'a, T, V>;
+
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+/// An iterator over the keys and elements of a queue, from front to back.
+pub struct EnumDequeIter<'a, T: EnumArrayHelper<V>, V: 'a> {
+  _phantom: marker::PhantomData<T>,
+  word: T::Word,
+  iter: iter::Chain<slice::Iter<'a, V>, slice::Iter<'a, V>>,
+}
+
+impl<'a, T: EnumArrayHelper<V>, V> Iterator for EnumDequeIter<'a, T, V> {
+  type Item = (T, &'a V);
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    let value = self.iter.next()?;
+    let key = unsafe { T::from_word_unchecked(self.word) };
+    self.word = self.word.inc();
+    Some((key, value))
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.iter.size_hint()
+  }
+}
+
+impl<'a, T: EnumArrayHelper<V>, V> iter::FusedIterator
+  for EnumDequeIter<'a, T, V>
+{
+}
@@ -0,0 +1,50 @@
+use crate::base::Enumoid;
+use core::fmt;
+
+/// Trait for fieldless enums which convert to and from their discriminants.
+///
+/// Impls are generated by `#[derive(Enumoid)]` with
+/// `#[enumoid(discriminant)]`, along with `From<T> for T::Repr` and
+/// `TryFrom<T::Repr> for T`. The discriminants are independent of the dense
+/// indices returned by `Enumoid::into_word`.
+pub trait EnumDiscriminant: Enumoid {
+  /// The integer `repr` of the enum, or `isize` if it has none.
+  type Repr: Copy;
+
+  /// Returns the discriminant of the value.
+  fn into_discriminant(self) -> Self::Repr;
+
+  /// Returns the value with the given discriminant.
+  fn from_discriminant(
+    value: Self::Repr,
+  ) -> Result<Self, DiscriminantError<Self::Repr>>;
+}
+
+/// The error returned when converting an integer which is not the
+/// discriminant of any variant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DiscriminantError<R> {
+  value: R,
+}
+
+impl<R> DiscriminantError<R> {
+  /// Creates an error for the rejected value.
+  #[inline]
+  pub const fn new(value: R) -> Self {
+    DiscriminantError { value }
+  }
+
+  /// Returns the rejected value.
+  #[inline]
+  pub fn into_value(self) -> R {
+    self.value
+  }
+}
+
+impl<R: fmt::Display> fmt::Display for DiscriminantError<R> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} is not the discriminant of any variant", self.value)
+  }
+}
+
+impl<R: fmt::Debug + fmt::Display> core::error::Error for DiscriminantError<R> {}
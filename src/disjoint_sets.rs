@@ -0,0 +1,257 @@
+use crate::EnumIndex;
+use crate::base::EnumArrayHelper;
+use crate::base::EnumSetHelper;
+use crate::map::EnumMap;
+use crate::set::EnumSet;
+use core::fmt;
+use core::fmt::Debug;
+use core::iter;
+
+/// A partition of enumoid `T`'s members into disjoint classes.
+///
+/// This is a union-find structure with union by rank and path compression.
+/// Each class is identified by a representative member, which may change as
+/// classes are merged.
+pub struct EnumDisjointSets<
+  T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<u8>,
+> {
+  parents: EnumMap<T, EnumIndex<T>>,
+  ranks: EnumMap<T, u8>,
+  classes: usize,
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<u8>>
+  EnumDisjointSets<T>
+{
+  /// Creates a new partition in which every member is in a class by itself.
+  pub fn new() -> Self {
+    EnumDisjointSets {
+      parents: EnumMap::new_with(EnumIndex::from_value),
+      ranks: EnumMap::new_with(|_| 0),
+      classes: T::SIZE,
+    }
+  }
+
+  fn root(&self, mut index: EnumIndex<T>) -> EnumIndex<T> {
+    while self.parents[index] != index {
+      index = self.parents[index];
+    }
+    index
+  }
+
+  /// Returns the representative of the class containing an index, without
+  /// compressing the path to it.
+  #[inline]
+  pub fn representative_by_index(&self, index: EnumIndex<T>) -> EnumIndex<T> {
+    self.root(index)
+  }
+
+  /// Returns the representative of the class containing a member, without
+  /// compressing the path to it.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn representative(&self, key: T) -> T {
+    self.root(key.into()).into_value()
+  }
+
+  /// Returns the representative of the class containing an index.
+  pub fn find_by_index(&mut self, index: EnumIndex<T>) -> EnumIndex<T> {
+    let root = self.root(index);
+    let mut index = index;
+    while index != root {
+      let parent = self.parents[index];
+      self.parents[index] = root;
+      index = parent;
+    }
+    root
+  }
+
+  /// Returns the representative of the class containing a member.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn find(&mut self, key: T) -> T {
+    self.find_by_index(key.into()).into_value()
+  }
+
+  /// Merges the classes containing two indices and returns true if they were
+  /// previously separate.
+  pub fn union_by_index(&mut self, a: EnumIndex<T>, b: EnumIndex<T>) -> bool {
+    let a = self.find_by_index(a);
+    let b = self.find_by_index(b);
+    if a == b {
+      return false;
+    }
+    let (child, parent) = if self.ranks[a] < self.ranks[b] {
+      (a, b)
+    } else {
+      (b, a)
+    };
+    self.parents[child] = parent;
+    if self.ranks[child] == self.ranks[parent] {
+      self.ranks[parent] += 1;
+    }
+    self.classes -= 1;
+    true
+  }
+
+  /// Merges the classes containing two members and returns true if they were
+  /// previously separate.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn union(&mut self, a: T, b: T) -> bool {
+    self.union_by_index(a.into(), b.into())
+  }
+
+  /// Returns true if two indices are in the same class.
+  #[inline]
+  pub fn same_set_by_index(
+    &mut self,
+    a: EnumIndex<T>,
+    b: EnumIndex<T>,
+  ) -> bool {
+    self.find_by_index(a) == self.find_by_index(b)
+  }
+
+  /// Returns true if two members are in the same class.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn same_set(&mut self, a: T, b: T) -> bool {
+    self.same_set_by_index(a.into(), b.into())
+  }
+
+  /// Returns the number of classes.
+  #[inline]
+  pub fn num_classes(&self) -> usize {
+    self.classes
+  }
+
+  /// Splits every class so that each member is in a class by itself.
+  pub fn clear(&mut self) {
+    *self = Self::new();
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<u8> + EnumSetHelper<u8>>
+  EnumDisjointSets<T>
+{
+  /// Returns the set of members in the same class as a given index.
+  pub fn class_of_index(&self, index: EnumIndex<T>) -> EnumSet<T> {
+    let root = self.root(index);
+    let mut class = EnumSet::new();
+    for key in T::iter() {
+      let member = EnumIndex::from_value(key);
+      if self.root(member) == root {
+        class.insert_by_index(member);
+      }
+    }
+    class
+  }
+
+  /// Returns the set of members in the same class as a given member.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn class_of(&self, key: T) -> EnumSet<T> {
+    self.class_of_index(key.into())
+  }
+
+  /// Returns an iterator over the classes, ordered by their lowest member.
+  pub fn classes(&self) -> EnumDisjointSetsClasses<'_, T> {
+    EnumDisjointSetsClasses {
+      sets: self,
+      seen: EnumSet::new(),
+      next: Some(EnumIndex::from_value(T::FIRST)),
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<u8>> Clone
+  for EnumDisjointSets<T>
+{
+  fn clone(&self) -> Self {
+    EnumDisjointSets {
+      parents: self.parents.clone(),
+      ranks: self.ranks.clone(),
+      classes: self.classes,
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<u8>> Copy
+  for EnumDisjointSets<T>
+where
+  <T as EnumArrayHelper<EnumIndex<T>>>::TotalArray: Copy,
+  <T as EnumArrayHelper<u8>>::TotalArray: Copy,
+{
+}
+
+impl<
+  T: EnumArrayHelper<EnumIndex<T>>
+    + EnumArrayHelper<u8>
+    + EnumSetHelper<u8>
+    + Debug,
+> Debug for EnumDisjointSets<T>
+{
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt.debug_list().entries(self.classes()).finish()
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<u8>> Default
+  for EnumDisjointSets<T>
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// An iterator over the classes of a partition.
+pub struct EnumDisjointSetsClasses<
+  'a,
+  T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<u8> + EnumSetHelper<u8>,
+> {
+  sets: &'a EnumDisjointSets<T>,
+  seen: EnumSet<T>,
+  next: Option<EnumIndex<T>>,
+}
+
+impl<
+  'a,
+  T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<u8> + EnumSetHelper<u8>,
+> Iterator for EnumDisjointSetsClasses<'a, T>
+{
+  type Item = EnumSet<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let mut index = self.next?;
+    while self.seen.contains_index(index) {
+      index = match index.next() {
+        Some(index) => index,
+        None => {
+          self.next = None;
+          return None;
+        }
+      };
+    }
+    self.next = index.next();
+    let class = self.sets.class_of_index(index);
+    self.seen.union_with(&class);
+    Some(class)
+  }
+}
+
+impl<
+  'a,
+  T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<u8> + EnumSetHelper<u8>,
+> iter::FusedIterator for EnumDisjointSetsClasses<'a, T>
+{
+}
@@ -0,0 +1,474 @@
+use crate::EnumIndex;
+use crate::base::EnumArrayHelper;
+use crate::base::EnumSetHelper;
+use crate::base::Enumoid;
+use crate::map::EnumMap;
+use crate::opt_map::EnumOptionMap;
+use crate::set::EnumSet;
+use crate::sub_base::BitsetWordTrait;
+use crate::sub_base::CheckedArithTrait;
+use crate::vec::EnumVec;
+use core::fmt;
+use core::fmt::Debug;
+
+fn indices<T: Enumoid>() -> impl Iterator<Item = EnumIndex<T>> {
+  T::iter().map(EnumIndex::from_value)
+}
+
+fn into_values<T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<T>>(
+  vec: EnumVec<T, EnumIndex<T>>,
+) -> EnumVec<T, T> {
+  vec
+    .as_slice()
+    .iter()
+    .map(|index| index.into_value())
+    .collect()
+}
+
+/// The state of an iterative depth-first search.
+///
+/// `stack` holds the path from the root of the current walk, so it never
+/// contains more than one copy of each node.
+struct DepthFirst<
+  T: EnumArrayHelper<EnumIndex<T>> + EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> {
+  visited: EnumSet<T, BitsetWord>,
+  stack: EnumVec<T, EnumIndex<T>>,
+}
+
+impl<
+  T: EnumArrayHelper<EnumIndex<T>> + EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> DepthFirst<T, BitsetWord>
+{
+  fn new() -> Self {
+    DepthFirst {
+      visited: EnumSet::new(),
+      stack: EnumVec::new(),
+    }
+  }
+
+  /// Walks the nodes reachable from `start` which have not been visited yet,
+  /// calling `pre` as each node is discovered and `post` once all of its
+  /// successors have been finished. `next_child` returns the first
+  /// successor of a node which is absent from the visited set.
+  fn walk<N, Pre, Post>(
+    &mut self,
+    start: EnumIndex<T>,
+    mut next_child: N,
+    mut pre: Pre,
+    mut post: Post,
+  ) where
+    N: FnMut(EnumIndex<T>, &EnumSet<T, BitsetWord>) -> Option<EnumIndex<T>>,
+    Pre: FnMut(EnumIndex<T>),
+    Post: FnMut(EnumIndex<T>),
+  {
+    if self.visited.insert_by_index(start) {
+      return;
+    }
+    pre(start);
+    let _ = self.stack.try_push(start);
+    while let Some(&node) = self.stack.as_slice().last() {
+      match next_child(node, &self.visited) {
+        Some(child) => {
+          self.visited.insert_by_index(child);
+          pre(child);
+          let _ = self.stack.try_push(child);
+        }
+        None => {
+          self.stack.pop();
+          post(node);
+        }
+      }
+    }
+  }
+}
+
+/// Graph algorithms over an adjacency map, in which each node is mapped to
+/// the set of its successors.
+///
+/// An `EnumRelation<T, T>` can be treated as a graph through
+/// [`EnumRelation::as_map`](crate::EnumRelation::as_map). None of these
+/// methods allocate; their working state is held in fixed-size enumoid
+/// containers.
+impl<
+  T: EnumArrayHelper<EnumSet<T, BitsetWord>>
+    + EnumArrayHelper<EnumIndex<T>>
+    + EnumArrayHelper<T>
+    + EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> EnumMap<T, EnumSet<T, BitsetWord>>
+{
+  fn successors(&self, index: EnumIndex<T>) -> &EnumSet<T, BitsetWord> {
+    &self[index]
+  }
+
+  fn next_successor(
+    &self,
+    node: EnumIndex<T>,
+    visited: &EnumSet<T, BitsetWord>,
+  ) -> Option<EnumIndex<T>> {
+    self
+      .successors(node)
+      .iter_index()
+      .find(|&child| !visited.contains_index(child))
+  }
+
+  fn next_predecessor(
+    &self,
+    node: EnumIndex<T>,
+    visited: &EnumSet<T, BitsetWord>,
+  ) -> Option<EnumIndex<T>> {
+    indices().find(|&parent| {
+      !visited.contains_index(parent)
+        && self.successors(parent).contains_index(node)
+    })
+  }
+
+  fn bfs_indices(&self, start: EnumIndex<T>) -> EnumVec<T, EnumIndex<T>> {
+    let mut visited = EnumSet::<T, BitsetWord>::new();
+    let mut order = EnumVec::<T, EnumIndex<T>>::new();
+    visited.insert_by_index(start);
+    let _ = order.try_push(start);
+    // The visit order doubles as the queue, as each node enters it once.
+    let mut head = 0;
+    while let Some(&node) = order.as_slice().get(head) {
+      head += 1;
+      for child in self.successors(node).iter_index() {
+        if !visited.insert_by_index(child) {
+          let _ = order.try_push(child);
+        }
+      }
+    }
+    order
+  }
+
+  fn path_indices(
+    &self,
+    from: EnumIndex<T>,
+    to: EnumIndex<T>,
+  ) -> Option<EnumVec<T, EnumIndex<T>>> {
+    let mut parents: EnumMap<T, EnumIndex<T>> =
+      EnumMap::new_with(EnumIndex::from_value);
+    let mut visited = EnumSet::<T, BitsetWord>::new();
+    let mut queue = EnumVec::<T, EnumIndex<T>>::new();
+    visited.insert_by_index(from);
+    let _ = queue.try_push(from);
+    let mut head = 0;
+    while to != from && !visited.contains_index(to) {
+      let &node = queue.as_slice().get(head)?;
+      head += 1;
+      for child in self.successors(node).iter_index() {
+        if !visited.insert_by_index(child) {
+          parents[child] = node;
+          let _ = queue.try_push(child);
+        }
+      }
+    }
+    let mut path = EnumVec::<T, EnumIndex<T>>::new();
+    let mut node = to;
+    let _ = path.try_push(node);
+    while node != from {
+      node = parents[node];
+      let _ = path.try_push(node);
+    }
+    path.as_slice_mut().reverse();
+    Some(path)
+  }
+
+  /// Returns the set of nodes reachable from a given node, including the
+  /// node itself.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  pub fn reachable_from(&self, start: T) -> EnumSet<T, BitsetWord> {
+    let mut reachable = EnumSet::new();
+    for &index in self.bfs_indices(start.into()).as_slice() {
+      reachable.insert_by_index(index);
+    }
+    reachable
+  }
+
+  /// Returns the nodes reachable from a given node in breadth-first order.
+  ///
+  /// The successors of each node are visited in index order.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  pub fn bfs_order(&self, start: T) -> EnumVec<T, T> {
+    into_values(self.bfs_indices(start.into()))
+  }
+
+  /// Returns the nodes reachable from a given node in depth-first preorder.
+  ///
+  /// The successors of each node are visited in index order.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  pub fn dfs_order(&self, start: T) -> EnumVec<T, T> {
+    let mut order = EnumVec::new();
+    DepthFirst::<T, BitsetWord>::new().walk(
+      start.into(),
+      |node, visited| self.next_successor(node, visited),
+      |node| {
+        let _ = order.try_push(node.into_value());
+      },
+      |_| {},
+    );
+    order
+  }
+
+  /// Returns a path with the fewest edges between two nodes, or `None` if
+  /// `to` is not reachable from `from`.
+  ///
+  /// The path includes both ends, so the path from a node to itself contains
+  /// only that node.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  pub fn shortest_path(&self, from: T, to: T) -> Option<EnumVec<T, T>> {
+    self.path_indices(from.into(), to.into()).map(into_values)
+  }
+
+  /// Returns the nodes ordered so that every node comes before its
+  /// successors.
+  ///
+  /// If the graph contains a cycle then a cycle is returned as the error
+  /// instead. Each node in the cycle is succeeded by the next, and the last
+  /// node is succeeded by the first.
+  pub fn topological_sort(&self) -> Result<EnumVec<T, T>, EnumVec<T, T>> {
+    let mut search = DepthFirst::<T, BitsetWord>::new();
+    let mut order = EnumVec::<T, EnumIndex<T>>::new();
+    for start in indices() {
+      search.walk(
+        start,
+        |node, visited| self.next_successor(node, visited),
+        |_| {},
+        |node| {
+          let _ = order.try_push(node);
+        },
+      );
+    }
+    order.as_slice_mut().reverse();
+    let mut position: EnumMap<T, EnumIndex<T>> =
+      EnumMap::new_with(EnumIndex::from_value);
+    for (position_index, &node) in order.iter() {
+      position[node] = EnumIndex::from_value(position_index);
+    }
+    for node in indices() {
+      for child in self.successors(node).iter_index() {
+        if position[child] <= position[node] {
+          // An edge against the order closes a cycle through the tree path.
+          let cycle = self.path_indices(child, node).unwrap();
+          return Err(into_values(cycle));
+        }
+      }
+    }
+    Ok(into_values(order))
+  }
+
+  /// Returns the strongly connected components of the graph, mapping each
+  /// node to the lowest node in its component.
+  pub fn strongly_connected_components(&self) -> EnumMap<T, T> {
+    let mut search = DepthFirst::<T, BitsetWord>::new();
+    let mut finished = EnumVec::<T, EnumIndex<T>>::new();
+    for start in indices() {
+      search.walk(
+        start,
+        |node, visited| self.next_successor(node, visited),
+        |_| {},
+        |node| {
+          let _ = finished.try_push(node);
+        },
+      );
+    }
+    let mut components: EnumMap<T, EnumIndex<T>> =
+      EnumMap::new_with(EnumIndex::from_value);
+    let mut search = DepthFirst::<T, BitsetWord>::new();
+    let mut members = EnumVec::<T, EnumIndex<T>>::new();
+    for &root in finished.as_slice().iter().rev() {
+      members.clear();
+      search.walk(
+        root,
+        |node, visited| self.next_predecessor(node, visited),
+        |node| {
+          let _ = members.try_push(node);
+        },
+        |_| {},
+      );
+      if let Some(&lowest) = members.as_slice().iter().min() {
+        for &member in members.as_slice() {
+          components[member] = lowest;
+        }
+      }
+    }
+    EnumMap::new_with(|key: T| components[key].into_value())
+  }
+}
+
+/// Shortest path algorithms over a weighted adjacency map, in which each node
+/// is mapped to the weights of the edges to its successors.
+impl<
+  T: EnumArrayHelper<EnumOptionMap<T, W, BitsetWord>>
+    + EnumArrayHelper<W>
+    + EnumArrayHelper<EnumIndex<T>>
+    + EnumSetHelper<BitsetWord>,
+  W: CheckedArithTrait + Default + Ord,
+  BitsetWord: BitsetWordTrait,
+> EnumMap<T, EnumOptionMap<T, W, BitsetWord>>
+{
+  /// Returns the lightest paths from a given node to every node reachable
+  /// from it, using Dijkstra's algorithm.
+  ///
+  /// The weight of a path is the sum of its edge weights, starting from
+  /// `W::default()`. Ties between nodes are broken in index order.
+  ///
+  /// # Panics
+  ///
+  /// Panics if an edge has a weight less than `W::default()`, if a path
+  /// weight overflows, or if `source` is skipped, as described for
+  /// [`Enumoid::into_word`].
+  pub fn shortest_paths(
+    &self,
+    source: T,
+  ) -> EnumShortestPaths<T, W, BitsetWord> {
+    let zero = W::default();
+    let mut paths = EnumShortestPaths {
+      distances: EnumOptionMap::new(),
+      predecessors: EnumOptionMap::new(),
+    };
+    let mut done = EnumSet::<T, BitsetWord>::new();
+    paths.distances.set(source, Some(zero));
+    loop {
+      let mut nearest = None;
+      for index in indices::<T>() {
+        if done.contains_index(index) {
+          continue;
+        }
+        if let Some(&distance) = paths.distances.get_by_index(index) {
+          if nearest.is_none_or(|(_, best)| distance < best) {
+            nearest = Some((index, distance));
+          }
+        }
+      }
+      let Some((node, distance)) = nearest else {
+        break;
+      };
+      done.insert_by_index(node);
+      for (child, &weight) in self[node].iter() {
+        assert!(weight >= zero, "Shortest path edge weight is negative");
+        let child = EnumIndex::from_value(child);
+        let total = distance
+          .checked_add(weight)
+          .expect("Shortest path weight overflowed");
+        if paths
+          .distances
+          .get_by_index(child)
+          .is_none_or(|&current| total < current)
+        {
+          paths.distances.set_by_index(child, Some(total));
+          paths.predecessors.set_by_index(child, Some(node));
+        }
+      }
+    }
+    paths
+  }
+}
+
+/// The lightest paths from a source node, as found by
+/// [`EnumMap::shortest_paths`].
+pub struct EnumShortestPaths<
+  T: EnumArrayHelper<W>
+    + EnumArrayHelper<EnumIndex<T>>
+    + EnumSetHelper<BitsetWord>,
+  W,
+  BitsetWord: BitsetWordTrait = u8,
+> {
+  distances: EnumOptionMap<T, W, BitsetWord>,
+  predecessors: EnumOptionMap<T, EnumIndex<T>, BitsetWord>,
+}
+
+impl<
+  T: EnumArrayHelper<W>
+    + EnumArrayHelper<EnumIndex<T>>
+    + EnumSetHelper<BitsetWord>,
+  W: Copy,
+  BitsetWord: BitsetWordTrait,
+> EnumShortestPaths<T, W, BitsetWord>
+{
+  /// Returns the weights of the paths to every reachable node.
+  #[inline]
+  pub fn distances(&self) -> &EnumOptionMap<T, W, BitsetWord> {
+    &self.distances
+  }
+
+  /// Returns the weight of the path to an index, or `None` if it is not
+  /// reachable.
+  #[inline]
+  pub fn distance_by_index(&self, index: EnumIndex<T>) -> Option<W> {
+    self.distances.get_by_index(index).copied()
+  }
+
+  /// Returns the weight of the path to a node, or `None` if it is not
+  /// reachable.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn distance(&self, target: T) -> Option<W> {
+    self.distance_by_index(target.into())
+  }
+
+  /// Returns the node before a given node on its path, or `None` if it is the
+  /// source or is not reachable.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn predecessor(&self, target: T) -> Option<T> {
+    self
+      .predecessors
+      .get(target)
+      .map(|index| index.into_value())
+  }
+
+  /// Returns the path from the source to a given node, including both ends,
+  /// or `None` if it is not reachable.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  pub fn path_to(&self, target: T) -> Option<EnumVec<T, T>>
+  where
+    T: EnumArrayHelper<T>,
+  {
+    let mut node = EnumIndex::from_value(target);
+    self.distances.get_by_index(node)?;
+    let mut path = EnumVec::<T, EnumIndex<T>>::new();
+    let _ = path.try_push(node);
+    while let Some(&previous) = self.predecessors.get_by_index(node) {
+      node = previous;
+      let _ = path.try_push(node);
+    }
+    path.as_slice_mut().reverse();
+    Some(into_values(path))
+  }
+}
+
+impl<
+  T: EnumArrayHelper<W>
+    + EnumArrayHelper<EnumIndex<T>>
+    + EnumSetHelper<BitsetWord>
+    + Debug,
+  W: Debug,
+  BitsetWord: BitsetWordTrait,
+> Debug for EnumShortestPaths<T, W, BitsetWord>
+{
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("EnumShortestPaths")
+      .field("distances", &self.distances)
+      .field("predecessors", &self.predecessors)
+      .finish()
+  }
+}
@@ -1,8 +1,8 @@
 use crate::base::Enumoid;
 use crate::sub_base::RawSizeWord;
-use std::iter::FusedIterator;
-use std::marker;
-use std::slice;
+use core::iter::FusedIterator;
+use core::marker;
+use core::slice;
 
 pub struct EnumSliceIter<'a, T: Enumoid, V: 'a> {
   pub(crate) _phantom: marker::PhantomData<T>,
@@ -78,3 +78,47 @@ impl<'a, T: Enumoid, V> DoubleEndedIterator for EnumSliceIterMut<'a, T, V> {
 }
 
 impl<'a, T: Enumoid, V> FusedIterator for EnumSliceIterMut<'a, T, V> {}
+
+/// Returns the first key whose value is preferred by `better` over the values
+/// of all the preceding keys, so that ties resolve to the lowest index.
+pub(crate) fn select_key_by<'a, T, V: 'a, I, F>(
+  mut iter: I,
+  mut better: F,
+) -> Option<T>
+where
+  I: Iterator<Item = (T, &'a V)>,
+  F: FnMut(&V, &V) -> bool,
+{
+  let (mut best_key, mut best) = iter.next()?;
+  for (key, value) in iter {
+    if better(value, best) {
+      best_key = key;
+      best = value;
+    }
+  }
+  Some(best_key)
+}
+
+/// As `select_key_by`, but compares the keys of type `K` derived from each
+/// value, computing each one only once.
+pub(crate) fn select_key_by_key<'a, T, V: 'a, K, I, F, B>(
+  mut iter: I,
+  mut f: F,
+  mut better: B,
+) -> Option<T>
+where
+  I: Iterator<Item = (T, &'a V)>,
+  F: FnMut(&V) -> K,
+  B: FnMut(&K, &K) -> bool,
+{
+  let (mut best_key, value) = iter.next()?;
+  let mut best = f(value);
+  for (key, value) in iter {
+    let k = f(value);
+    if better(&k, &best) {
+      best_key = key;
+      best = k;
+    }
+  }
+  Some(best_key)
+}
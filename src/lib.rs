@@ -1,24 +1,66 @@
 #![doc = include_str!("../README.md")]
+#![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod bag;
 #[macro_use]
 mod base;
+mod bimap;
+mod deque;
+mod discriminant;
+mod disjoint_sets;
+mod graph;
 mod iter;
 mod map;
+#[cfg(feature = "std")]
+mod once_map;
 mod opt_map;
+mod packed_map;
+mod permutation;
+mod priority_queue;
+mod relation;
 #[cfg(feature = "serde")]
 mod serde;
 mod set;
 mod sub_base;
 mod vec;
 
+pub use bag::EnumBag;
 pub use base::EnumArrayHelper;
 pub use base::EnumIndex;
+pub use base::EnumPackHelper;
 pub use base::EnumSetHelper;
 pub use base::EnumSize;
 pub use base::Enumoid;
+#[doc(hidden)]
+pub use base::{
+  ArrayConcat, AutoWord, AutoWordHelper, auto_word_tier, concat_slice,
+  concat_slice_mut,
+};
+pub use bimap::EnumBiMap;
+pub use deque::EnumDeque;
+pub use discriminant::DiscriminantError;
+pub use discriminant::EnumDiscriminant;
+pub use disjoint_sets::EnumDisjointSets;
+pub use graph::EnumShortestPaths;
 pub use map::EnumMap;
+#[cfg(feature = "std")]
+pub use once_map::EnumOnceMap;
 pub use opt_map::EnumOptionMap;
+pub use packed_map::EnumPackedMap;
+pub use permutation::EnumPermutation;
+pub use permutation::Parity;
+pub use priority_queue::EnumPriorityQueue;
+pub use relation::EnumRelation;
 pub use set::EnumSet;
+pub use sub_base::BagCountTrait;
+pub use sub_base::CheckedArithTrait;
+#[doc(hidden)]
+pub use sub_base::RawSizeWord;
 pub use vec::EnumVec;
 
 // Re-export derive macro
@@ -3,17 +3,30 @@ use crate::base::EnumArrayHelper;
 use crate::base::EnumSetHelper;
 use crate::iter::EnumSliceIter;
 use crate::iter::EnumSliceIterMut;
+use crate::iter::select_key_by;
+use crate::iter::select_key_by_key;
 use crate::opt_map::EnumOptionMap;
+use crate::sub_base::CheckedArithTrait;
 use crate::sub_base::RawSizeWord;
-use std::convert::TryFrom;
-use std::fmt;
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::iter;
-use std::mem;
-use std::ops::{Index, IndexMut};
+use crate::vec::EnumVec;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::iter;
+use core::mem;
+use core::ops::{
+  Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub,
+  SubAssign,
+};
 
 /// A total map from enumoid `T` to values `V`.
+///
+/// When `V` is numeric, the arithmetic operators apply element-wise, and
+/// multiplication and division by a single `V` scale every value.
 pub struct EnumMap<T: EnumArrayHelper<V>, V> {
   data: T::TotalArray,
 }
@@ -23,6 +36,12 @@ impl<T: EnumArrayHelper<V>, V: Default> EnumMap<T, V> {
   pub fn new() -> Self {
     Self::new_with(|_| Default::default())
   }
+
+  /// Creates a new map on the heap populated with default values.
+  #[cfg(feature = "alloc")]
+  pub fn new_boxed() -> Box<Self> {
+    Self::new_with_boxed(|_| Default::default())
+  }
 }
 
 impl<T: EnumArrayHelper<V>, V> EnumMap<T, V> {
@@ -41,6 +60,26 @@ impl<T: EnumArrayHelper<V>, V> EnumMap<T, V> {
     }
   }
 
+  /// Creates a new map on the heap populated by a callback function.
+  ///
+  /// The values are written directly into the heap allocation, so the map
+  /// never needs to fit on the stack.
+  #[cfg(feature = "alloc")]
+  pub fn new_with_boxed<F>(mut f: F) -> Box<Self>
+  where
+    F: FnMut(T) -> V,
+  {
+    let mut boxed = Box::<Self>::new_uninit();
+    let ptr = boxed.as_mut_ptr();
+    // The partial and total arrays share a layout, and the partial array is
+    // valid while uninitialised.
+    let arr = unsafe { &mut *(&raw mut (*ptr).data as *mut T::PartialArray) };
+    for (key, cell) in T::iter().zip(T::partial_slice_mut(arr).iter_mut()) {
+      cell.write(f(key));
+    }
+    unsafe { boxed.assume_init() }
+  }
+
   /// Returns a slice containing all the values in the map.
   #[inline]
   pub fn as_slice(&self) -> &[V] {
@@ -60,6 +99,9 @@ impl<T: EnumArrayHelper<V>, V> EnumMap<T, V> {
   }
 
   /// Returns a reference to the value associated with a given key.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn get(&self, key: T) -> &V {
     &self[key]
@@ -72,11 +114,28 @@ impl<T: EnumArrayHelper<V>, V> EnumMap<T, V> {
   }
 
   /// Returns a mutable reference to the value associated with a given key.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn get_mut(&mut self, key: T) -> &mut V {
     &mut self[key]
   }
 
+  /// Returns a reference to the value associated with a given key, or `None`
+  /// if the key is skipped.
+  #[inline]
+  pub fn try_get(&self, key: T) -> Option<&V> {
+    EnumIndex::try_from_value(key).map(|index| &self[index])
+  }
+
+  /// Returns a mutable reference to the value associated with a given key, or
+  /// `None` if the key is skipped.
+  #[inline]
+  pub fn try_get_mut(&mut self, key: T) -> Option<&mut V> {
+    EnumIndex::try_from_value(key).map(|index| &mut self[index])
+  }
+
   /// Sets the value associated with a given index and returns the old value.
   #[inline]
   pub fn set_by_index(&mut self, index: EnumIndex<T>, value: V) -> V {
@@ -84,6 +143,9 @@ impl<T: EnumArrayHelper<V>, V> EnumMap<T, V> {
   }
 
   /// Sets the value associated with a given key and returns the old value.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn set(&mut self, key: T, value: V) -> V {
     self.set_by_index(key.into(), value)
@@ -96,6 +158,9 @@ impl<T: EnumArrayHelper<V>, V> EnumMap<T, V> {
   }
 
   /// Swaps two elements in the map.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn swap(&mut self, a: T, b: T) {
     self.swap_by_index(a.into(), b.into())
@@ -122,6 +187,76 @@ impl<T: EnumArrayHelper<V>, V> EnumMap<T, V> {
   }
 }
 
+impl<T: EnumArrayHelper<V>, V> EnumMap<T, V> {
+  /// Returns the key whose value maps to the largest result of a callback
+  /// function.
+  ///
+  /// If several keys share the largest result, the one with the lowest index
+  /// is returned.
+  pub fn argmax_by_key<K: Ord, F>(&self, f: F) -> T
+  where
+    F: FnMut(&V) -> K,
+  {
+    // Enumoids are always inhabited, so there is at least one entry.
+    select_key_by_key(self.iter(), f, |a, b| a > b).unwrap()
+  }
+
+  /// Returns the key whose value maps to the smallest result of a callback
+  /// function.
+  ///
+  /// If several keys share the smallest result, the one with the lowest index
+  /// is returned.
+  pub fn argmin_by_key<K: Ord, F>(&self, f: F) -> T
+  where
+    F: FnMut(&V) -> K,
+  {
+    select_key_by_key(self.iter(), f, |a, b| a < b).unwrap()
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Ord> EnumMap<T, V> {
+  /// Returns the key with the largest value.
+  ///
+  /// If several keys share the largest value, the one with the lowest index
+  /// is returned.
+  pub fn argmax(&self) -> T {
+    select_key_by(self.iter(), |a, b| a > b).unwrap()
+  }
+
+  /// Returns the key with the smallest value.
+  ///
+  /// If several keys share the smallest value, the one with the lowest index
+  /// is returned.
+  pub fn argmin(&self) -> T {
+    select_key_by(self.iter(), |a, b| a < b).unwrap()
+  }
+
+  /// Returns the keys with the smallest and largest values.
+  ///
+  /// Ties are resolved to the lowest index as for `argmin` and `argmax`.
+  pub fn min_max(&self) -> (T, T) {
+    (self.argmin(), self.argmax())
+  }
+}
+
+impl<T: EnumArrayHelper<V> + EnumArrayHelper<T> + Copy, V> EnumMap<T, V> {
+  /// Returns all the keys sorted by comparing their values with a callback
+  /// function.
+  ///
+  /// Keys whose values compare equal are ordered by their index.
+  pub fn keys_sorted_by<F>(&self, mut compare: F) -> EnumVec<T, T>
+  where
+    F: FnMut(&V, &V) -> Ordering,
+  {
+    let mut keys: EnumVec<T, T> = T::iter().collect();
+    keys.as_slice_mut().sort_unstable_by(|&a, &b| {
+      compare(&self[a], &self[b])
+        .then_with(|| a.into_word().cmp(&b.into_word()))
+    });
+    keys
+  }
+}
+
 impl<T: EnumArrayHelper<V>, V: Clone> Clone for EnumMap<T, V> {
   fn clone(&self) -> Self {
     Self::new_with(|k| self.get(k).clone())
@@ -154,7 +289,7 @@ impl<T: EnumArrayHelper<V>, V: PartialEq> PartialEq for EnumMap<T, V> {
 impl<T: EnumArrayHelper<V>, V: Eq> Eq for EnumMap<T, V> {}
 
 impl<T: EnumArrayHelper<V>, V: Hash> Hash for EnumMap<T, V> {
-  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
     self.as_slice().hash(state);
   }
 }
@@ -171,6 +306,8 @@ impl<T: EnumArrayHelper<V>, V> Index<EnumIndex<T>> for EnumMap<T, V> {
 impl<T: EnumArrayHelper<V>, V> Index<T> for EnumMap<T, V> {
   type Output = V;
 
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   fn index(&self, key: T) -> &V {
     &self[EnumIndex::from_value(key)]
@@ -185,6 +322,8 @@ impl<T: EnumArrayHelper<V>, V> IndexMut<EnumIndex<T>> for EnumMap<T, V> {
 }
 
 impl<T: EnumArrayHelper<V>, V> IndexMut<T> for EnumMap<T, V> {
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   fn index_mut(&mut self, key: T) -> &mut V {
     &mut self[EnumIndex::from_value(key)]
@@ -322,3 +461,181 @@ impl<T: EnumArrayHelper<V> + EnumSetHelper<u8>, V> TryFrom<EnumOptionMap<T, V>>
     }
   }
 }
+
+impl<T: EnumArrayHelper<V>, V: Copy> EnumMap<T, V> {
+  fn zip_apply<F>(&mut self, other: &Self, mut f: F)
+  where
+    F: FnMut(V, V) -> V,
+  {
+    for (a, &b) in self.as_slice_mut().iter_mut().zip(other.as_slice()) {
+      *a = f(*a, b);
+    }
+  }
+
+  fn try_zip_apply<F>(&mut self, other: &Self, mut f: F) -> Option<()>
+  where
+    F: FnMut(V, V) -> Option<V>,
+  {
+    for (a, &b) in self.as_slice_mut().iter_mut().zip(other.as_slice()) {
+      *a = f(*a, b)?;
+    }
+    Some(())
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + PartialOrd> EnumMap<T, V> {
+  /// Returns a map containing the smaller of each pair of values.
+  pub fn min_elementwise(&self, other: &Self) -> Self {
+    let mut result = self.clone();
+    result.zip_apply(other, |a, b| if b < a { b } else { a });
+    result
+  }
+
+  /// Returns a map containing the larger of each pair of values.
+  pub fn max_elementwise(&self, other: &Self) -> Self {
+    let mut result = self.clone();
+    result.zip_apply(other, |a, b| if b > a { b } else { a });
+    result
+  }
+}
+
+impl<
+  T: EnumArrayHelper<V>,
+  V: Copy + Default + Add<Output = V> + Mul<Output = V>,
+> EnumMap<T, V>
+{
+  /// Returns the sum of the products of each pair of values.
+  pub fn dot(&self, other: &Self) -> V {
+    self
+      .as_slice()
+      .iter()
+      .zip(other.as_slice())
+      .fold(V::default(), |acc, (&a, &b)| acc + a * b)
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: CheckedArithTrait> EnumMap<T, V> {
+  /// Adds each pair of values, or returns `None` if any addition overflows.
+  pub fn checked_add(&self, other: &Self) -> Option<Self> {
+    let mut result = self.clone();
+    result.try_zip_apply(other, V::checked_add)?;
+    Some(result)
+  }
+
+  /// Subtracts each pair of values, or returns `None` if any subtraction
+  /// overflows.
+  pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+    let mut result = self.clone();
+    result.try_zip_apply(other, V::checked_sub)?;
+    Some(result)
+  }
+
+  /// Adds each pair of values, saturating at the numeric bounds.
+  pub fn saturating_add(&self, other: &Self) -> Self {
+    let mut result = self.clone();
+    result.zip_apply(other, V::saturating_add);
+    result
+  }
+
+  /// Subtracts each pair of values, saturating at the numeric bounds.
+  pub fn saturating_sub(&self, other: &Self) -> Self {
+    let mut result = self.clone();
+    result.zip_apply(other, V::saturating_sub);
+    result
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + Add<Output = V>> Add for EnumMap<T, V> {
+  type Output = Self;
+
+  fn add(mut self, rhs: Self) -> Self {
+    self.zip_apply(&rhs, V::add);
+    self
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + Add<Output = V>> AddAssign
+  for EnumMap<T, V>
+{
+  fn add_assign(&mut self, rhs: Self) {
+    self.zip_apply(&rhs, V::add);
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + Sub<Output = V>> Sub for EnumMap<T, V> {
+  type Output = Self;
+
+  fn sub(mut self, rhs: Self) -> Self {
+    self.zip_apply(&rhs, V::sub);
+    self
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + Sub<Output = V>> SubAssign
+  for EnumMap<T, V>
+{
+  fn sub_assign(&mut self, rhs: Self) {
+    self.zip_apply(&rhs, V::sub);
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + Neg<Output = V>> Neg for EnumMap<T, V> {
+  type Output = Self;
+
+  fn neg(mut self) -> Self {
+    for a in self.as_slice_mut() {
+      *a = -*a;
+    }
+    self
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + Mul<Output = V>> Mul<V>
+  for EnumMap<T, V>
+{
+  type Output = Self;
+
+  fn mul(mut self, rhs: V) -> Self {
+    self *= rhs;
+    self
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + Mul<Output = V>> MulAssign<V>
+  for EnumMap<T, V>
+{
+  fn mul_assign(&mut self, rhs: V) {
+    for a in self.as_slice_mut() {
+      *a = *a * rhs;
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + Div<Output = V>> Div<V>
+  for EnumMap<T, V>
+{
+  type Output = Self;
+
+  fn div(mut self, rhs: V) -> Self {
+    self /= rhs;
+    self
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + Div<Output = V>> DivAssign<V>
+  for EnumMap<T, V>
+{
+  fn div_assign(&mut self, rhs: V) {
+    for a in self.as_slice_mut() {
+      *a = *a / rhs;
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<V>, V: Copy + Default + Add<Output = V>> iter::Sum
+  for EnumMap<T, V>
+{
+  fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.fold(Self::new(), |acc, map| acc + map)
+  }
+}
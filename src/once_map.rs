@@ -0,0 +1,235 @@
+use crate::EnumIndex;
+use crate::base::EnumArrayHelper;
+use crate::base::EnumSetHelper;
+use crate::iter::EnumSliceIter;
+use crate::opt_map::EnumOptionMap;
+use crate::sub_base::RawSizeWord;
+use std::fmt;
+use std::fmt::Debug;
+use std::iter;
+use std::mem;
+use std::sync::OnceLock;
+
+/// A map from enumoid `T` to lazily initialised values `V`.
+///
+/// Each slot behaves like a [`OnceLock`], so values can be initialised
+/// through a shared reference and the map can be stored in a `static`.
+pub struct EnumOnceMap<T: EnumArrayHelper<OnceLock<V>>, V> {
+  data: T::TotalArray,
+}
+
+impl<T: EnumArrayHelper<OnceLock<V>>, V> EnumOnceMap<T, V> {
+  /// Creates a new map with all slots uninitialised.
+  pub const fn new() -> Self {
+    let mut arr = mem::MaybeUninit::<T::TotalArray>::uninit();
+    let ptr = arr.as_mut_ptr() as *mut OnceLock<V>;
+    let mut i = 0;
+    while i < T::SIZE {
+      unsafe { ptr.add(i).write(OnceLock::new()) };
+      i += 1;
+    }
+    EnumOnceMap {
+      data: unsafe { arr.assume_init() },
+    }
+  }
+
+  #[inline]
+  fn cell(&self, index: EnumIndex<T>) -> &OnceLock<V> {
+    unsafe { T::total_slice(&self.data).get_unchecked(index.into_usize()) }
+  }
+
+  /// Returns a reference to the value associated with a given index,
+  /// or `None` if the slot has not been initialised.
+  #[inline]
+  pub fn get_by_index(&self, index: EnumIndex<T>) -> Option<&V> {
+    self.cell(index).get()
+  }
+
+  /// Returns a reference to the value associated with a given key,
+  /// or `None` if the slot has not been initialised.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn get(&self, key: T) -> Option<&V> {
+    self.get_by_index(key.into())
+  }
+
+  /// Returns a mutable reference to the value associated with a given index,
+  /// or `None` if the slot has not been initialised.
+  #[inline]
+  pub fn get_by_index_mut(&mut self, index: EnumIndex<T>) -> Option<&mut V> {
+    T::total_slice_mut(&mut self.data)[index.into_usize()].get_mut()
+  }
+
+  /// Returns a mutable reference to the value associated with a given key,
+  /// or `None` if the slot has not been initialised.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn get_mut(&mut self, key: T) -> Option<&mut V> {
+    self.get_by_index_mut(key.into())
+  }
+
+  /// Returns the value associated with a given index, initialising it with a
+  /// callback function if the slot is empty.
+  ///
+  /// As with [`OnceLock::get_or_init`], concurrent callers for the same slot
+  /// block until the first initialisation has finished.
+  #[inline]
+  pub fn get_or_init_by_index<F>(&self, index: EnumIndex<T>, f: F) -> &V
+  where
+    F: FnOnce() -> V,
+  {
+    self.cell(index).get_or_init(f)
+  }
+
+  /// Returns the value associated with a given key, initialising it with a
+  /// callback function if the slot is empty.
+  ///
+  /// As with [`OnceLock::get_or_init`], concurrent callers for the same slot
+  /// block until the first initialisation has finished.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn get_or_init<F>(&self, key: T, f: F) -> &V
+  where
+    F: FnOnce() -> V,
+  {
+    self.get_or_init_by_index(key.into(), f)
+  }
+
+  /// Initialises the slot at a given index, or returns the value back if the
+  /// slot was already initialised.
+  #[inline]
+  pub fn set_by_index(&self, index: EnumIndex<T>, value: V) -> Result<(), V> {
+    self.cell(index).set(value)
+  }
+
+  /// Initialises the slot for a given key, or returns the value back if the
+  /// slot was already initialised.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn set(&self, key: T, value: V) -> Result<(), V> {
+    self.set_by_index(key.into(), value)
+  }
+
+  /// Returns true if the slot at a given index has been initialised.
+  #[inline]
+  pub fn contains_index(&self, index: EnumIndex<T>) -> bool {
+    self.get_by_index(index).is_some()
+  }
+
+  /// Returns true if the slot for a given key has been initialised.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn contains(&self, key: T) -> bool {
+    self.contains_index(key.into())
+  }
+
+  /// Returns an iterator over the keys and values of the initialised slots.
+  #[inline]
+  pub fn iter_initialized(&self) -> EnumOnceMapIter<'_, T, V> {
+    EnumOnceMapIter {
+      iter: EnumSliceIter {
+        _phantom: Default::default(),
+        word: T::Word::ZERO,
+        iter: T::total_slice(&self.data).iter(),
+      },
+    }
+  }
+}
+
+impl<
+  T: EnumArrayHelper<OnceLock<V>> + EnumArrayHelper<V> + EnumSetHelper<u8>,
+  V,
+> EnumOnceMap<T, V>
+{
+  /// Converts the map into a partial map of the initialised slots.
+  pub fn into_option_map(self) -> EnumOptionMap<T, V> {
+    // `EnumOnceMap` has no `Drop`, so the array can be moved out directly.
+    let mut data =
+      <T as EnumArrayHelper<OnceLock<V>>>::total_to_partial(self.data);
+    let mut map = EnumOptionMap::new();
+    for (key, cell) in T::iter().zip(
+      <T as EnumArrayHelper<OnceLock<V>>>::partial_slice_mut(&mut data)
+        .iter_mut(),
+    ) {
+      if let Some(value) = unsafe { cell.assume_init_read() }.into_inner() {
+        map.insert(key, value);
+      }
+    }
+    map
+  }
+}
+
+impl<T: EnumArrayHelper<OnceLock<V>> + Debug, V: Debug> Debug
+  for EnumOnceMap<T, V>
+{
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt.debug_map().entries(self.iter_initialized()).finish()
+  }
+}
+
+impl<T: EnumArrayHelper<OnceLock<V>>, V> Default for EnumOnceMap<T, V> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T: EnumArrayHelper<OnceLock<V>>, V: Clone> Clone for EnumOnceMap<T, V> {
+  fn clone(&self) -> Self {
+    let clone = Self::new();
+    for (key, value) in self.iter_initialized() {
+      let _ = clone.set(key, value.clone());
+    }
+    clone
+  }
+}
+
+impl<'a, T: EnumArrayHelper<OnceLock<V>>, V> iter::IntoIterator
+  for &'a EnumOnceMap<T, V>
+{
+  type Item = (T, &'a V);
+  type IntoIter = EnumOnceMapIter<'a, T, V>;
+
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_initialized()
+  }
+}
+
+/// An iterator over the keys and values of the initialised slots of a map.
+pub struct EnumOnceMapIter<'a, T: EnumArrayHelper<OnceLock<V>>, V> {
+  iter: EnumSliceIter<'a, T, OnceLock<V>>,
+}
+
+impl<'a, T: EnumArrayHelper<OnceLock<V>>, V> Iterator
+  for EnumOnceMapIter<'a, T, V>
+{
+  type Item = (T, &'a V);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    for (key, cell) in self.iter.by_ref() {
+      if let Some(value) = cell.get() {
+        return Some((key, value));
+      }
+    }
+    None
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, self.iter.size_hint().1)
+  }
+}
+
+impl<'a, T: EnumArrayHelper<OnceLock<V>>, V> iter::FusedIterator
+  for EnumOnceMapIter<'a, T, V>
+{
+}
@@ -2,16 +2,22 @@ use crate::EnumIndex;
 use crate::base::EnumArrayHelper;
 use crate::base::EnumSetHelper;
 use crate::base::EnumSize;
+use crate::iter::select_key_by;
+use crate::iter::select_key_by_key;
 use crate::set::EnumSet;
 use crate::set::EnumSetIndexIter;
 use crate::sub_base::BitsetWordTrait;
+use crate::vec::EnumVec;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::cmp::Ordering;
+use core::fmt;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::iter;
+use core::mem;
+use core::mem::MaybeUninit;
 use core::slice;
-use std::fmt;
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::iter;
-use std::mem;
-use std::mem::MaybeUninit;
 
 /// A partial map from enumoid `T` to values `V`.
 ///
@@ -40,6 +46,19 @@ impl<
     }
   }
 
+  /// Creates a new empty map on the heap without building it on the stack.
+  #[cfg(feature = "alloc")]
+  pub fn new_boxed() -> Box<Self> {
+    let mut boxed = Box::<Self>::new_uninit();
+    let ptr = boxed.as_mut_ptr();
+    // The data array is valid while uninitialised, so only the validity
+    // bitmap needs to be written.
+    unsafe {
+      (&raw mut (*ptr).valid).write(EnumSet::new());
+      boxed.assume_init()
+    }
+  }
+
   /// Returns a reference to the value associated with a given index,
   /// or `None` if the index has no value in the map.
   #[inline]
@@ -55,6 +74,9 @@ impl<
 
   /// Returns a reference to the value associated with a given key,
   /// or `None` if the key has no value in the map.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn get(&self, key: T) -> Option<&V> {
     self.get_by_index(key.into())
@@ -76,11 +98,21 @@ impl<
 
   /// Returns a mutable reference to the value associated with a given key,
   /// or `None` if the key has no value in the map.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn get_mut(&mut self, key: T) -> Option<&mut V> {
     self.get_by_index_mut(key.into())
   }
 
+  /// Returns a reference to the value associated with a given key, or `None`
+  /// if the key is skipped or has no value in the map.
+  #[inline]
+  pub fn try_get(&self, key: T) -> Option<&V> {
+    self.get_by_index(EnumIndex::try_from_value(key)?)
+  }
+
   /// Sets the value associated with a given index and returns the old value if one was present.
   #[inline]
   pub fn set_by_index(
@@ -102,6 +134,9 @@ impl<
   }
 
   /// Sets the value associated with a given key and returns the old value if one was present.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn set(&mut self, key: T, value: Option<V>) -> Option<V> {
     self.set_by_index(key.into(), value)
@@ -118,11 +153,24 @@ impl<
   }
 
   /// Adds a value with the given key to the map and returns the old value if one was present.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn insert(&mut self, key: T, value: V) -> Option<V> {
     self.insert_by_index(key.into(), value)
   }
 
+  /// Adds a value with the given key to the map and returns the old value if
+  /// one was present, or returns the value as an error if the key is skipped.
+  #[inline]
+  pub fn try_insert(&mut self, key: T, value: V) -> Result<Option<V>, V> {
+    match EnumIndex::try_from_value(key) {
+      Some(index) => Ok(self.insert_by_index(index, value)),
+      None => Err(value),
+    }
+  }
+
   /// Removes any value at the given index from the map and returns it if one was present.
   #[inline]
   pub fn remove_by_index(&mut self, index: EnumIndex<T>) -> Option<V> {
@@ -130,6 +178,9 @@ impl<
   }
 
   /// Removes any value with the given key from the map and returns it if one was present.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn remove(&mut self, key: T) -> Option<V> {
     self.remove_by_index(key.into())
@@ -155,6 +206,9 @@ impl<
   }
 
   /// Swaps two elements in the map.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn swap(&mut self, a: T, b: T) {
     self.swap_by_index(a.into(), b.into());
@@ -206,6 +260,9 @@ impl<
   }
 
   /// Returns true if the map contains the key.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn contains(&self, value: T) -> bool {
     self.valid.contains(value)
@@ -248,6 +305,94 @@ impl<
   }
 }
 
+impl<
+  T: EnumArrayHelper<V> + EnumSetHelper<BitsetWord>,
+  V,
+  BitsetWord: BitsetWordTrait,
+> EnumOptionMap<T, V, BitsetWord>
+{
+  /// Returns the present key whose value maps to the largest result of a
+  /// callback function, or `None` if the map is empty.
+  ///
+  /// If several keys share the largest result, the one with the lowest index
+  /// is returned.
+  pub fn argmax_by_key<K: Ord, F>(&self, f: F) -> Option<T>
+  where
+    F: FnMut(&V) -> K,
+  {
+    select_key_by_key(self.iter(), f, |a, b| a > b)
+  }
+
+  /// Returns the present key whose value maps to the smallest result of a
+  /// callback function, or `None` if the map is empty.
+  ///
+  /// If several keys share the smallest result, the one with the lowest index
+  /// is returned.
+  pub fn argmin_by_key<K: Ord, F>(&self, f: F) -> Option<T>
+  where
+    F: FnMut(&V) -> K,
+  {
+    select_key_by_key(self.iter(), f, |a, b| a < b)
+  }
+}
+
+impl<
+  T: EnumArrayHelper<V> + EnumSetHelper<BitsetWord>,
+  V: Ord,
+  BitsetWord: BitsetWordTrait,
+> EnumOptionMap<T, V, BitsetWord>
+{
+  /// Returns the present key with the largest value, or `None` if the map is
+  /// empty.
+  ///
+  /// If several keys share the largest value, the one with the lowest index
+  /// is returned.
+  pub fn argmax(&self) -> Option<T> {
+    select_key_by(self.iter(), |a, b| a > b)
+  }
+
+  /// Returns the present key with the smallest value, or `None` if the map is
+  /// empty.
+  ///
+  /// If several keys share the smallest value, the one with the lowest index
+  /// is returned.
+  pub fn argmin(&self) -> Option<T> {
+    select_key_by(self.iter(), |a, b| a < b)
+  }
+
+  /// Returns the present keys with the smallest and largest values, or `None`
+  /// if the map is empty.
+  ///
+  /// Ties are resolved to the lowest index as for `argmin` and `argmax`.
+  pub fn min_max(&self) -> Option<(T, T)> {
+    Some((self.argmin()?, self.argmax()?))
+  }
+}
+
+impl<
+  T: EnumArrayHelper<V> + EnumArrayHelper<T> + EnumSetHelper<BitsetWord> + Copy,
+  V,
+  BitsetWord: BitsetWordTrait,
+> EnumOptionMap<T, V, BitsetWord>
+{
+  /// Returns the present keys sorted by comparing their values with a
+  /// callback function.
+  ///
+  /// Keys whose values compare equal are ordered by their index.
+  pub fn keys_sorted_by<F>(&self, mut compare: F) -> EnumVec<T, T>
+  where
+    F: FnMut(&V, &V) -> Ordering,
+  {
+    let mut keys: EnumVec<T, T> = self.iter().map(|(key, _)| key).collect();
+    keys.as_slice_mut().sort_unstable_by(|&a, &b| {
+      // Both keys were collected from the populated entries.
+      let (va, vb) = (self.get(a).unwrap(), self.get(b).unwrap());
+      compare(va, vb).then_with(|| a.into_word().cmp(&b.into_word()))
+    });
+    keys
+  }
+}
+
 impl<
   T: EnumArrayHelper<V> + EnumSetHelper<BitsetWord> + Debug,
   V: Debug,
@@ -312,7 +457,7 @@ impl<
   BitsetWord: BitsetWordTrait,
 > Hash for EnumOptionMap<T, V, BitsetWord>
 {
-  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
     for key in T::iter() {
       self.get(key).hash(state);
     }
@@ -0,0 +1,328 @@
+use crate::EnumIndex;
+use crate::base::EnumArrayHelper;
+use crate::base::EnumPackHelper;
+use crate::base::EnumSetHelper;
+use crate::map::EnumMap;
+use crate::set::EnumSet;
+use crate::sub_base::BitsetWordTrait;
+use crate::sub_base::RawSizeWord;
+use core::fmt;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::iter;
+
+/// A total map from enumoid `K` to enumoid `V`, storing each value in
+/// `ceil(log2(V::SIZE))` bits.
+///
+/// The optional type parameter `BitsetWord` is passed on to the planes.
+///
+/// # Layout
+///
+/// The values are stored as bit planes, in place of bit fields packed within
+/// each word: bit `b` of each value's index is held in the `b`th plane, an
+/// `EnumSet<K>`. This uses the same number of bits per value, can be sized on
+/// stable Rust, and lets `keys_of` and `count_of` work a word at a time.
+pub struct EnumPackedMap<
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait = u8,
+> {
+  planes: V::PlaneArray,
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> EnumPackedMap<K, V, BitsetWord>
+{
+  /// Creates a new map with every key mapped to `V::FIRST`.
+  fn new_first() -> Self {
+    EnumPackedMap {
+      planes: V::new_planes(EnumSet::new),
+    }
+  }
+
+  /// Creates a new map populated by a callback function.
+  pub fn new_with<F>(mut f: F) -> Self
+  where
+    F: FnMut(K) -> V,
+  {
+    let mut map = Self::new_first();
+    for key in K::iter() {
+      let index = EnumIndex::from_value(key);
+      map.set_by_index(index, f(index.into_value()));
+    }
+    map
+  }
+
+  /// Returns the value associated with a given index.
+  #[inline]
+  pub fn get_by_index(&self, index: EnumIndex<K>) -> V {
+    let mut word = 0;
+    for (bit, plane) in V::plane_slice(&self.planes).iter().enumerate() {
+      if plane.contains_index(index) {
+        word |= 1 << bit;
+      }
+    }
+    // Only the indices of values are ever stored in the planes.
+    unsafe {
+      EnumIndex::<V>::from_word_unchecked(V::Word::from_usize_unchecked(word))
+    }
+    .into_value()
+  }
+
+  /// Returns the value associated with a given key.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn get(&self, key: K) -> V {
+    self.get_by_index(key.into())
+  }
+
+  /// Sets the value associated with a given index and returns the previous
+  /// value.
+  pub fn set_by_index(&mut self, index: EnumIndex<K>, value: V) -> V {
+    let old = self.get_by_index(index);
+    let word = EnumIndex::from_value(value).into_usize();
+    for (bit, plane) in
+      V::plane_slice_mut(&mut self.planes).iter_mut().enumerate()
+    {
+      plane.set_by_index(index, (word >> bit) & 1 != 0);
+    }
+    old
+  }
+
+  /// Sets the value associated with a given key and returns the previous
+  /// value.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn set(&mut self, key: K, value: V) -> V {
+    self.set_by_index(key.into(), value)
+  }
+
+  /// Returns the set of keys which are mapped to a given value.
+  pub fn keys_of(&self, value: V) -> EnumSet<K, BitsetWord> {
+    let word = EnumIndex::from_value(value).into_usize();
+    let mut keys = EnumSet::new_all();
+    for (bit, plane) in V::plane_slice(&self.planes).iter().enumerate() {
+      if (word >> bit) & 1 != 0 {
+        keys.intersect_with(plane);
+      } else {
+        keys = keys.difference(plane);
+      }
+    }
+    keys
+  }
+
+  /// Returns the number of keys which are mapped to a given value.
+  #[inline]
+  pub fn count_of(&self, value: V) -> usize {
+    self.keys_of(value).count()
+  }
+
+  /// Returns an iterator over the keys and values.
+  #[inline]
+  pub fn iter(&self) -> EnumPackedMapIter<'_, K, V, BitsetWord> {
+    EnumPackedMapIter {
+      map: self,
+      next: Some(EnumIndex::from_value(K::FIRST)),
+    }
+  }
+
+  /// Returns an unpacked copy of the map.
+  pub fn to_map(&self) -> EnumMap<K, V>
+  where
+    K: EnumArrayHelper<V>,
+  {
+    EnumMap::new_with(|key| self.get(key))
+  }
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>> + Default,
+  BitsetWord: BitsetWordTrait,
+> EnumPackedMap<K, V, BitsetWord>
+{
+  /// Creates a new map with every key mapped to the default value.
+  pub fn new() -> Self {
+    let value = EnumIndex::from_value(V::default());
+    Self::new_with(|_| value.into_value())
+  }
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> Clone for EnumPackedMap<K, V, BitsetWord>
+{
+  fn clone(&self) -> Self {
+    // The plane array may hold spare planes beyond `PACKED_BITS`.
+    let mut planes = V::plane_slice(&self.planes).iter();
+    EnumPackedMap {
+      planes: V::new_planes(|| {
+        planes.next().map_or_else(EnumSet::new, EnumSet::duplicate)
+      }),
+    }
+  }
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> Copy for EnumPackedMap<K, V, BitsetWord>
+where
+  V::PlaneArray: Copy,
+{
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord> + Debug,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>> + Debug,
+  BitsetWord: BitsetWordTrait,
+> Debug for EnumPackedMap<K, V, BitsetWord>
+{
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt.debug_map().entries(self.iter()).finish()
+  }
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>> + Default,
+  BitsetWord: BitsetWordTrait,
+> Default for EnumPackedMap<K, V, BitsetWord>
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> PartialEq for EnumPackedMap<K, V, BitsetWord>
+{
+  fn eq(&self, other: &Self) -> bool {
+    V::plane_slice(&self.planes) == V::plane_slice(&other.planes)
+  }
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> Eq for EnumPackedMap<K, V, BitsetWord>
+{
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> Hash for EnumPackedMap<K, V, BitsetWord>
+{
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+    V::plane_slice(&self.planes).hash(state);
+  }
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord> + EnumArrayHelper<V>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> From<EnumMap<K, V>> for EnumPackedMap<K, V, BitsetWord>
+{
+  fn from(map: EnumMap<K, V>) -> Self {
+    let mut packed = Self::new_first();
+    for (key, value) in map {
+      packed.set(key, value);
+    }
+    packed
+  }
+}
+
+impl<
+  K: EnumSetHelper<BitsetWord> + EnumArrayHelper<V>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> From<EnumPackedMap<K, V, BitsetWord>> for EnumMap<K, V>
+{
+  fn from(packed: EnumPackedMap<K, V, BitsetWord>) -> Self {
+    packed.to_map()
+  }
+}
+
+impl<
+  'a,
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> iter::IntoIterator for &'a EnumPackedMap<K, V, BitsetWord>
+{
+  type Item = (K, V);
+  type IntoIter = EnumPackedMapIter<'a, K, V, BitsetWord>;
+
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+/// An iterator over the keys and values of a packed map.
+pub struct EnumPackedMapIter<
+  'a,
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> {
+  map: &'a EnumPackedMap<K, V, BitsetWord>,
+  next: Option<EnumIndex<K>>,
+}
+
+impl<
+  'a,
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> Iterator for EnumPackedMapIter<'a, K, V, BitsetWord>
+{
+  type Item = (K, V);
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    let index = self.next?;
+    self.next = index.next();
+    Some((index.into_value(), self.map.get_by_index(index)))
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let remaining = self.next.map_or(0, |index| K::SIZE - index.into_usize());
+    (remaining, Some(remaining))
+  }
+}
+
+impl<
+  'a,
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> ExactSizeIterator for EnumPackedMapIter<'a, K, V, BitsetWord>
+{
+}
+
+impl<
+  'a,
+  K: EnumSetHelper<BitsetWord>,
+  V: EnumPackHelper<EnumSet<K, BitsetWord>>,
+  BitsetWord: BitsetWordTrait,
+> iter::FusedIterator for EnumPackedMapIter<'a, K, V, BitsetWord>
+{
+}
@@ -0,0 +1,386 @@
+use crate::EnumIndex;
+use crate::base::EnumArrayHelper;
+use crate::base::EnumSetHelper;
+use crate::map::EnumMap;
+use crate::set::EnumSet;
+use crate::vec::EnumVec;
+use core::fmt;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::iter;
+
+/// The parity of a permutation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Parity {
+  /// The permutation is a product of an even number of transpositions.
+  Even,
+  /// The permutation is a product of an odd number of transpositions.
+  Odd,
+}
+
+/// A bijection from enumoid `T` onto itself.
+///
+/// The mapping is stored in both directions so that the permutation and its
+/// inverse can both be applied in constant time.
+pub struct EnumPermutation<T: EnumArrayHelper<EnumIndex<T>>> {
+  forward: EnumMap<T, EnumIndex<T>>,
+  inverse: EnumMap<T, EnumIndex<T>>,
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> EnumPermutation<T> {
+  /// Creates the identity permutation.
+  pub fn identity() -> Self {
+    EnumPermutation {
+      forward: EnumMap::new_with(EnumIndex::from_value),
+      inverse: EnumMap::new_with(EnumIndex::from_value),
+    }
+  }
+
+  /// Creates a permutation from the images of each value in order, or returns
+  /// `None` if the vector is not full, contains a value more than once, or
+  /// contains a skipped value.
+  pub fn from_order(order: EnumVec<T, T>) -> Option<Self>
+  where
+    T: EnumArrayHelper<T> + EnumSetHelper<u8>,
+  {
+    if !order.is_full() {
+      return None;
+    }
+    let mut seen = EnumSet::<T>::new();
+    let mut perm = Self::identity();
+    for (key, value) in order.into_iter().enumerate() {
+      let index = EnumIndex::try_from_value(value)?;
+      if seen.insert_by_index(index) {
+        return None;
+      }
+      // The vector is full, so every position is a valid index.
+      let key = EnumIndex::from_usize(key).unwrap();
+      perm.forward[key] = index;
+      perm.inverse[index] = key;
+    }
+    Some(perm)
+  }
+
+  /// Returns the images of each value in order.
+  pub fn order(&self) -> EnumVec<T, T>
+  where
+    T: EnumArrayHelper<T>,
+  {
+    self
+      .forward
+      .iter()
+      .map(|(_, index)| index.into_value())
+      .collect()
+  }
+
+  /// Returns the image of an index.
+  #[inline]
+  pub fn apply_index(&self, index: EnumIndex<T>) -> EnumIndex<T> {
+    self.forward[index]
+  }
+
+  /// Returns the image of a value.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn apply(&self, value: T) -> T {
+    self.forward[value].into_value()
+  }
+
+  /// Returns the preimage of an index.
+  #[inline]
+  pub fn apply_inverse_index(&self, index: EnumIndex<T>) -> EnumIndex<T> {
+    self.inverse[index]
+  }
+
+  /// Returns the preimage of a value.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn apply_inverse(&self, value: T) -> T {
+    self.inverse[value].into_value()
+  }
+
+  /// Returns the inverse permutation.
+  pub fn inverse(&self) -> Self {
+    EnumPermutation {
+      forward: self.inverse.clone(),
+      inverse: self.forward.clone(),
+    }
+  }
+
+  /// Returns the composition which applies `other` and then `self`.
+  pub fn compose(&self, other: &Self) -> Self {
+    let forward = EnumMap::new_with(|key| self.forward[other.forward[key]]);
+    let inverse = EnumMap::new_with(|key| other.inverse[self.inverse[key]]);
+    EnumPermutation { forward, inverse }
+  }
+
+  /// Exchanges the images of two indices.
+  pub fn swap_by_index(&mut self, a: EnumIndex<T>, b: EnumIndex<T>) {
+    self.forward.swap_by_index(a, b);
+    self.inverse[self.forward[a]] = a;
+    self.inverse[self.forward[b]] = b;
+  }
+
+  /// Exchanges the images of two values.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  pub fn swap(&mut self, a: T, b: T) {
+    self.swap_by_index(a.into(), b.into())
+  }
+
+  /// Returns true if this is the identity permutation.
+  pub fn is_identity(&self) -> bool {
+    self
+      .forward
+      .iter()
+      .all(|(key, &index)| EnumIndex::from_value(key) == index)
+  }
+
+  /// Rearranges the images into the next permutation in lexicographic order,
+  /// or returns false if this is already the last one.
+  fn advance_lexicographic(&mut self) -> bool {
+    let slice = self.forward.as_slice_mut();
+    let Some(i) = (1..slice.len()).rev().find(|&i| slice[i - 1] < slice[i])
+    else {
+      return false;
+    };
+    let j = (i..slice.len())
+      .rev()
+      .find(|&j| slice[i - 1] < slice[j])
+      .unwrap();
+    slice.swap(i - 1, j);
+    slice[i..].reverse();
+    for (key, &index) in self.forward.iter() {
+      self.inverse[index] = EnumIndex::from_value(key);
+    }
+    true
+  }
+
+  /// Returns an iterator over every permutation of `T` in lexicographic order
+  /// of their images, starting with the identity.
+  pub fn iter_lexicographic() -> EnumPermutationLexIter<T> {
+    EnumPermutationLexIter {
+      next: Some(Self::identity()),
+    }
+  }
+
+  /// Returns an iterator over every permutation of `T` using Heap's
+  /// algorithm, starting with the identity.
+  ///
+  /// Each permutation differs from the previous one by a single swap, which
+  /// makes this cheaper than lexicographic order.
+  pub fn iter_heap() -> EnumPermutationHeapIter<T> {
+    EnumPermutationHeapIter {
+      current: Self::identity(),
+      counters: EnumMap::new_with(|_| EnumIndex::from_value(T::FIRST)),
+      position: EnumIndex::from_usize(1),
+      started: false,
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>> + EnumSetHelper<u8>> EnumPermutation<T> {
+  /// Returns an iterator over the disjoint cycles of the permutation.
+  ///
+  /// Every value appears in exactly one cycle, including fixed points as
+  /// cycles of length one. Each cycle starts with its lowest value and the
+  /// cycles are ordered by their first value.
+  pub fn cycles(&self) -> EnumPermutationCycles<'_, T> {
+    EnumPermutationCycles {
+      perm: self,
+      visited: EnumSet::new(),
+      next: Some(EnumIndex::from_value(T::FIRST)),
+    }
+  }
+
+  /// Returns the number of disjoint cycles, including fixed points.
+  pub fn cycle_count(&self) -> usize {
+    let mut visited = EnumSet::<T>::new();
+    let mut count = 0;
+    for key in T::iter() {
+      let start = EnumIndex::from_value(key);
+      if visited.contains_index(start) {
+        continue;
+      }
+      count += 1;
+      let mut index = start;
+      while !visited.insert_by_index(index) {
+        index = self.forward[index];
+      }
+    }
+    count
+  }
+
+  /// Returns the parity of the permutation.
+  pub fn parity(&self) -> Parity {
+    if (T::SIZE - self.cycle_count()) % 2 == 0 {
+      Parity::Even
+    } else {
+      Parity::Odd
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> Clone for EnumPermutation<T> {
+  fn clone(&self) -> Self {
+    EnumPermutation {
+      forward: self.forward.clone(),
+      inverse: self.inverse.clone(),
+    }
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> Copy for EnumPermutation<T> where
+  T::TotalArray: Copy
+{
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>> + Debug> Debug for EnumPermutation<T> {
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_map()
+      .entries(
+        self
+          .forward
+          .iter()
+          .map(|(key, index)| (key, index.into_value())),
+      )
+      .finish()
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> Default for EnumPermutation<T> {
+  fn default() -> Self {
+    Self::identity()
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> PartialEq for EnumPermutation<T> {
+  fn eq(&self, other: &Self) -> bool {
+    self.forward == other.forward
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> Eq for EnumPermutation<T> {}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> Hash for EnumPermutation<T> {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+    self.forward.hash(state);
+  }
+}
+
+/// An iterator over the disjoint cycles of a permutation.
+pub struct EnumPermutationCycles<
+  'a,
+  T: EnumArrayHelper<EnumIndex<T>> + EnumSetHelper<u8>,
+> {
+  perm: &'a EnumPermutation<T>,
+  visited: EnumSet<T>,
+  next: Option<EnumIndex<T>>,
+}
+
+impl<
+  'a,
+  T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<T> + EnumSetHelper<u8>,
+> Iterator for EnumPermutationCycles<'a, T>
+{
+  type Item = EnumVec<T, T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let mut start = self.next?;
+    while self.visited.contains_index(start) {
+      start = match start.next() {
+        Some(index) => index,
+        None => {
+          self.next = None;
+          return None;
+        }
+      };
+    }
+    self.next = start.next();
+    let mut cycle = EnumVec::new();
+    let mut index = start;
+    while !self.visited.insert_by_index(index) {
+      let _ = cycle.try_push(index.into_value());
+      index = self.perm.forward[index];
+    }
+    Some(cycle)
+  }
+}
+
+impl<
+  'a,
+  T: EnumArrayHelper<EnumIndex<T>> + EnumArrayHelper<T> + EnumSetHelper<u8>,
+> iter::FusedIterator for EnumPermutationCycles<'a, T>
+{
+}
+
+/// An iterator over every permutation of an enumoid in lexicographic order.
+pub struct EnumPermutationLexIter<T: EnumArrayHelper<EnumIndex<T>>> {
+  next: Option<EnumPermutation<T>>,
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> Iterator for EnumPermutationLexIter<T> {
+  type Item = EnumPermutation<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let current = self.next.take()?;
+    let mut next = current.clone();
+    if next.advance_lexicographic() {
+      self.next = Some(next);
+    }
+    Some(current)
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> iter::FusedIterator
+  for EnumPermutationLexIter<T>
+{
+}
+
+/// An iterator over every permutation of an enumoid using Heap's algorithm.
+///
+/// `counters` and `position` hold the state of the iterative form of the
+/// algorithm, with `position` becoming `None` once it has finished.
+pub struct EnumPermutationHeapIter<T: EnumArrayHelper<EnumIndex<T>>> {
+  current: EnumPermutation<T>,
+  counters: EnumMap<T, EnumIndex<T>>,
+  position: Option<EnumIndex<T>>,
+  started: bool,
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> Iterator for EnumPermutationHeapIter<T> {
+  type Item = EnumPermutation<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if !self.started {
+      self.started = true;
+      return Some(self.current.clone());
+    }
+    let first = EnumIndex::from_value(T::FIRST);
+    while let Some(i) = self.position {
+      let c = self.counters[i];
+      if c < i {
+        let other = if i.into_usize() % 2 == 0 { first } else { c };
+        self.current.swap_by_index(other, i);
+        // `c` is less than `i`, so its successor is a valid index.
+        self.counters[i] = c.next().unwrap();
+        self.position = first.next();
+        return Some(self.current.clone());
+      }
+      self.counters[i] = first;
+      self.position = i.next();
+    }
+    None
+  }
+}
+
+impl<T: EnumArrayHelper<EnumIndex<T>>> iter::FusedIterator
+  for EnumPermutationHeapIter<T>
+{
+}
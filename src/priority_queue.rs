@@ -0,0 +1,250 @@
+use crate::EnumIndex;
+use crate::base::EnumArrayHelper;
+use crate::map::EnumMap;
+use crate::vec::EnumVec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::fmt::Debug;
+use core::mem;
+
+/// A min-priority queue containing each value of enumoid `T` at most once,
+/// with a priority of type `P`.
+///
+/// The queue is a binary heap stored in an `EnumVec`, together with a map from
+/// each value to its position in the heap so that priorities can be updated
+/// and entries removed by key. Entries with equal priorities are ordered by
+/// their index.
+pub struct EnumPriorityQueue<
+  T: EnumArrayHelper<(EnumIndex<T>, P)> + EnumArrayHelper<Option<EnumIndex<T>>>,
+  P,
+> {
+  heap: EnumVec<T, (EnumIndex<T>, P)>,
+  positions: EnumMap<T, Option<EnumIndex<T>>>,
+}
+
+impl<
+  T: EnumArrayHelper<(EnumIndex<T>, P)> + EnumArrayHelper<Option<EnumIndex<T>>>,
+  P: Ord,
+> EnumPriorityQueue<T, P>
+{
+  /// Creates a new empty queue.
+  pub fn new() -> Self {
+    EnumPriorityQueue {
+      heap: EnumVec::new(),
+      positions: EnumMap::new_with(|_| None),
+    }
+  }
+
+  /// Returns true if the queue is empty.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.heap.is_empty()
+  }
+
+  /// Returns the number of entries in the queue.
+  #[inline]
+  pub fn count(&self) -> usize {
+    self.heap.as_slice().len()
+  }
+
+  /// Returns true if an index is in the queue.
+  #[inline]
+  pub fn contains_index(&self, index: EnumIndex<T>) -> bool {
+    self.positions[index].is_some()
+  }
+
+  /// Returns true if a value is in the queue.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn contains(&self, key: T) -> bool {
+    self.contains_index(key.into())
+  }
+
+  /// Returns the priority of an index, or `None` if it is not in the queue.
+  #[inline]
+  pub fn priority_by_index(&self, index: EnumIndex<T>) -> Option<&P> {
+    self.positions[index].map(|position| &self.heap[position].1)
+  }
+
+  /// Returns the priority of a value, or `None` if it is not in the queue.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn priority(&self, key: T) -> Option<&P> {
+    self.priority_by_index(key.into())
+  }
+
+  /// Returns the entry with the lowest priority without removing it.
+  pub fn peek(&self) -> Option<(T, &P)> {
+    let (index, priority) = self.heap.as_slice().first()?;
+    Some((index.into_value(), priority))
+  }
+
+  /// Adds an index to the queue, or changes its priority if it is already
+  /// present. Returns the previous priority, if any.
+  pub fn push_or_update_by_index(
+    &mut self,
+    index: EnumIndex<T>,
+    priority: P,
+  ) -> Option<P> {
+    match self.positions[index] {
+      Some(position) => {
+        let i = position.into_usize();
+        let old = mem::replace(&mut self.heap.as_slice_mut()[i].1, priority);
+        let i = self.sift_up(i);
+        self.sift_down(i);
+        Some(old)
+      }
+      None => {
+        let i = self.count();
+        // Each index is in the heap at most once, so it is never full here.
+        let _ = self.heap.try_push((index, priority));
+        self.positions[index] = Some(Self::position(i));
+        self.sift_up(i);
+        None
+      }
+    }
+  }
+
+  /// Adds a value to the queue, or changes its priority if it is already
+  /// present. Returns the previous priority, if any.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn push_or_update(&mut self, key: T, priority: P) -> Option<P> {
+    self.push_or_update_by_index(key.into(), priority)
+  }
+
+  /// Removes the entry with the lowest priority and returns it.
+  pub fn pop_min(&mut self) -> Option<(T, P)> {
+    let index = self.heap.as_slice().first()?.0;
+    let priority = self.remove_by_index(index)?;
+    Some((index.into_value(), priority))
+  }
+
+  /// Removes an index from the queue and returns its priority.
+  pub fn remove_by_index(&mut self, index: EnumIndex<T>) -> Option<P> {
+    let position = self.positions[index].take()?;
+    let (_, priority) = self.heap.swap_remove_at_index(position)?;
+    let i = position.into_usize();
+    if let Some(&(moved, _)) = self.heap.as_slice().get(i) {
+      self.positions[moved] = Some(position);
+      let i = self.sift_up(i);
+      self.sift_down(i);
+    }
+    Some(priority)
+  }
+
+  /// Removes a value from the queue and returns its priority.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn remove(&mut self, key: T) -> Option<P> {
+    self.remove_by_index(key.into())
+  }
+
+  /// Clears all the entries from the queue.
+  pub fn clear(&mut self) {
+    self.heap.clear();
+    for position in self.positions.as_slice_mut() {
+      *position = None;
+    }
+  }
+
+  fn position(i: usize) -> EnumIndex<T> {
+    // Heap positions are always less than the number of entries.
+    EnumIndex::from_usize(i).unwrap()
+  }
+
+  fn less(&self, i: usize, j: usize) -> bool {
+    let heap = self.heap.as_slice();
+    let (a, b) = (&heap[i], &heap[j]);
+    a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)) == Ordering::Less
+  }
+
+  fn swap(&mut self, i: usize, j: usize) {
+    let heap = self.heap.as_slice_mut();
+    heap.swap(i, j);
+    let (a, b) = (heap[i].0, heap[j].0);
+    self.positions[a] = Some(Self::position(i));
+    self.positions[b] = Some(Self::position(j));
+  }
+
+  fn sift_up(&mut self, mut i: usize) -> usize {
+    while i > 0 {
+      let parent = (i - 1) / 2;
+      if !self.less(i, parent) {
+        break;
+      }
+      self.swap(i, parent);
+      i = parent;
+    }
+    i
+  }
+
+  fn sift_down(&mut self, mut i: usize) {
+    let len = self.count();
+    loop {
+      let left = 2 * i + 1;
+      if left >= len {
+        break;
+      }
+      let right = left + 1;
+      let child = if right < len && self.less(right, left) {
+        right
+      } else {
+        left
+      };
+      if !self.less(child, i) {
+        break;
+      }
+      self.swap(child, i);
+      i = child;
+    }
+  }
+}
+
+impl<
+  T: EnumArrayHelper<(EnumIndex<T>, P)> + EnumArrayHelper<Option<EnumIndex<T>>>,
+  P: Clone,
+> Clone for EnumPriorityQueue<T, P>
+{
+  fn clone(&self) -> Self {
+    EnumPriorityQueue {
+      heap: self.heap.clone(),
+      positions: self.positions.clone(),
+    }
+  }
+}
+
+impl<
+  T: EnumArrayHelper<(EnumIndex<T>, P)>
+    + EnumArrayHelper<Option<EnumIndex<T>>>
+    + Debug,
+  P: Debug,
+> Debug for EnumPriorityQueue<T, P>
+{
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_map()
+      .entries(self.positions.iter().filter_map(|(key, position)| {
+        position.map(|position| (key, &self.heap[position].1))
+      }))
+      .finish()
+  }
+}
+
+impl<
+  T: EnumArrayHelper<(EnumIndex<T>, P)> + EnumArrayHelper<Option<EnumIndex<T>>>,
+  P: Ord,
+> Default for EnumPriorityQueue<T, P>
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
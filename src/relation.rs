@@ -0,0 +1,642 @@
+use crate::EnumIndex;
+use crate::base::EnumArrayHelper;
+use crate::base::EnumSetHelper;
+use crate::map::EnumMap;
+use crate::set::EnumSet;
+use crate::set::EnumSetIndexIter;
+use crate::sub_base::BitsetWordTrait;
+use core::fmt;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::iter;
+use core::ops;
+
+/// A binary relation between enumoids `A` and `B`.
+///
+/// The relation is stored as a bit matrix with one `EnumSet<B>` row for each
+/// value of `A`. The optional type parameter `BitsetWord` is passed on to the
+/// rows.
+pub struct EnumRelation<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait = u8,
+> {
+  rows: EnumMap<A, EnumSet<B, BitsetWord>>,
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> EnumRelation<A, B, BitsetWord>
+{
+  /// Creates a new empty relation.
+  pub fn new() -> Self {
+    EnumRelation {
+      rows: EnumMap::new_with(|_| EnumSet::new()),
+    }
+  }
+
+  /// Creates the relation in which every `A` is related to every `B`.
+  pub fn new_all() -> Self {
+    EnumRelation {
+      rows: EnumMap::new_with(|_| EnumSet::new_all()),
+    }
+  }
+
+  /// Returns a map from each value to the set of values it is related to.
+  ///
+  /// The graph algorithms on `EnumMap<T, EnumSet<T>>` can be applied to a
+  /// square relation through this map.
+  #[inline]
+  pub fn as_map(&self) -> &EnumMap<A, EnumSet<B, BitsetWord>> {
+    &self.rows
+  }
+
+  /// Sets whether two indices are related.
+  #[inline]
+  pub fn set_by_index(&mut self, a: EnumIndex<A>, b: EnumIndex<B>, flag: bool) {
+    self.rows[a].set_by_index(b, flag)
+  }
+
+  /// Sets whether two values are related.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn set(&mut self, a: A, b: B, flag: bool) {
+    self.set_by_index(a.into(), b.into(), flag)
+  }
+
+  /// Relates two indices and returns true if they were already related.
+  #[inline]
+  pub fn relate_by_index(&mut self, a: EnumIndex<A>, b: EnumIndex<B>) -> bool {
+    self.rows[a].insert_by_index(b)
+  }
+
+  /// Relates two values and returns true if they were already related.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn relate(&mut self, a: A, b: B) -> bool {
+    self.relate_by_index(a.into(), b.into())
+  }
+
+  /// Unrelates two indices and returns true if they were related.
+  #[inline]
+  pub fn unrelate_by_index(
+    &mut self,
+    a: EnumIndex<A>,
+    b: EnumIndex<B>,
+  ) -> bool {
+    self.rows[a].remove_by_index(b)
+  }
+
+  /// Unrelates two values and returns true if they were related.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn unrelate(&mut self, a: A, b: B) -> bool {
+    self.unrelate_by_index(a.into(), b.into())
+  }
+
+  /// Returns true if two indices are related.
+  #[inline]
+  pub fn related_by_index(&self, a: EnumIndex<A>, b: EnumIndex<B>) -> bool {
+    self.rows[a].contains_index(b)
+  }
+
+  /// Returns true if two values are related.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn related(&self, a: A, b: B) -> bool {
+    self.related_by_index(a.into(), b.into())
+  }
+
+  /// Returns the set of values related to a given index.
+  #[inline]
+  pub fn row_by_index(&self, a: EnumIndex<A>) -> &EnumSet<B, BitsetWord> {
+    &self.rows[a]
+  }
+
+  /// Returns the set of values related to a given value.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn row(&self, a: A) -> &EnumSet<B, BitsetWord> {
+    &self.rows[a]
+  }
+
+  /// Returns a mutable reference to the set of values related to a given
+  /// index.
+  #[inline]
+  pub fn row_by_index_mut(
+    &mut self,
+    a: EnumIndex<A>,
+  ) -> &mut EnumSet<B, BitsetWord> {
+    &mut self.rows[a]
+  }
+
+  /// Returns a mutable reference to the set of values related to a given
+  /// value.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  #[inline]
+  pub fn row_mut(&mut self, a: A) -> &mut EnumSet<B, BitsetWord> {
+    &mut self.rows[a]
+  }
+
+  /// Returns the set of values which a given index is related from.
+  pub fn column_by_index(&self, b: EnumIndex<B>) -> EnumSet<A, BitsetWord>
+  where
+    A: EnumSetHelper<BitsetWord>,
+  {
+    let mut column = EnumSet::new();
+    for (a, row) in self.rows.iter() {
+      if row.contains_index(b) {
+        column.insert(a);
+      }
+    }
+    column
+  }
+
+  /// Returns the set of values which a given value is related from.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
+  pub fn column(&self, b: B) -> EnumSet<A, BitsetWord>
+  where
+    A: EnumSetHelper<BitsetWord>,
+  {
+    self.column_by_index(b.into())
+  }
+
+  /// Returns the converse relation, in which `b` is related to `a` if `a` is
+  /// related to `b`.
+  pub fn transpose(&self) -> EnumRelation<B, A, BitsetWord>
+  where
+    A: EnumSetHelper<BitsetWord>,
+    B: EnumArrayHelper<EnumSet<A, BitsetWord>>,
+  {
+    let mut transposed = EnumRelation::new();
+    for (a, b) in self.iter_index() {
+      transposed.relate_by_index(b, a);
+    }
+    transposed
+  }
+
+  /// Returns the composition which relates `a` to `c` if `a` is related to
+  /// some `b` in `self` which is related to `c` in `other`.
+  pub fn compose<C: EnumSetHelper<BitsetWord>>(
+    &self,
+    other: &EnumRelation<B, C, BitsetWord>,
+  ) -> EnumRelation<A, C, BitsetWord>
+  where
+    A: EnumArrayHelper<EnumSet<C, BitsetWord>>,
+    B: EnumArrayHelper<EnumSet<C, BitsetWord>>,
+  {
+    EnumRelation {
+      rows: EnumMap::new_with(|a| {
+        let mut row = EnumSet::new();
+        for b in self.row(a).iter_index() {
+          row.union_with(other.row_by_index(b));
+        }
+        row
+      }),
+    }
+  }
+
+  /// Returns the relation containing the pairs in either `self` or `other`.
+  pub fn union(&self, other: &Self) -> Self {
+    let mut relation = self.clone();
+    relation.union_with(other);
+    relation
+  }
+
+  /// Returns the relation containing the pairs in both `self` and `other`.
+  pub fn intersection(&self, other: &Self) -> Self {
+    let mut relation = self.clone();
+    relation.intersect_with(other);
+    relation
+  }
+
+  /// Returns true if every pair in `self` is also in `other`.
+  pub fn is_subset(&self, other: &Self) -> bool {
+    self
+      .rows
+      .as_slice()
+      .iter()
+      .zip(other.rows.as_slice())
+      .all(|(a, b)| a.is_subset(b))
+  }
+
+  fn union_with(&mut self, other: &Self) {
+    for (a, b) in self
+      .rows
+      .as_slice_mut()
+      .iter_mut()
+      .zip(other.rows.as_slice())
+    {
+      a.union_with(b);
+    }
+  }
+
+  fn intersect_with(&mut self, other: &Self) {
+    for (a, b) in self
+      .rows
+      .as_slice_mut()
+      .iter_mut()
+      .zip(other.rows.as_slice())
+    {
+      a.intersect_with(b);
+    }
+  }
+
+  /// Clears all the pairs from the relation.
+  pub fn clear(&mut self) {
+    for row in self.rows.as_slice_mut() {
+      row.clear();
+    }
+  }
+
+  /// Returns true if no values are related.
+  pub fn is_empty(&self) -> bool {
+    !self.rows.as_slice().iter().any(EnumSet::any)
+  }
+
+  /// Returns the number of related pairs.
+  pub fn count(&self) -> usize {
+    self.rows.as_slice().iter().map(EnumSet::count).sum()
+  }
+
+  /// Returns an iterator over the indices of the related pairs.
+  #[inline]
+  pub fn iter_index(&self) -> EnumRelationIndexIter<'_, A, B, BitsetWord> {
+    EnumRelationIndexIter {
+      rows: &self.rows,
+      row: EnumIndex::from_value(A::FIRST),
+      iter: Some(self.rows[A::FIRST].iter_index()),
+    }
+  }
+
+  /// Returns an iterator over the related pairs.
+  #[inline]
+  pub fn iter(&self) -> EnumRelationIter<'_, A, B, BitsetWord> {
+    EnumRelationIter {
+      iter: self.iter_index(),
+    }
+  }
+}
+
+impl<
+  T: EnumArrayHelper<EnumSet<T, BitsetWord>> + EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> EnumRelation<T, T, BitsetWord>
+{
+  /// Creates the identity relation, in which every value is related only to
+  /// itself.
+  pub fn identity() -> Self {
+    let mut relation = Self::new();
+    for key in T::iter() {
+      let index = EnumIndex::from_value(key);
+      relation.relate_by_index(index, index);
+    }
+    relation
+  }
+
+  /// Returns true if every value is related to itself.
+  pub fn is_reflexive(&self) -> bool {
+    self.rows.iter().all(|(key, row)| row.contains(key))
+  }
+
+  /// Returns true if `b` is related to `a` whenever `a` is related to `b`.
+  pub fn is_symmetric(&self) -> bool {
+    self.iter_index().all(|(a, b)| self.related_by_index(b, a))
+  }
+
+  /// Returns true if `a` is related to `c` whenever `a` is related to some
+  /// `b` which is related to `c`.
+  pub fn is_transitive(&self) -> bool {
+    self
+      .rows
+      .as_slice()
+      .iter()
+      .all(|row| row.iter_index().all(|b| self.rows[b].is_subset(row)))
+  }
+
+  /// Returns the smallest reflexive relation containing `self`.
+  pub fn reflexive_closure(&self) -> Self {
+    self.union(&Self::identity())
+  }
+
+  /// Returns the smallest transitive relation containing `self`.
+  ///
+  /// This uses Warshall's algorithm, merging whole rows a word at a time.
+  pub fn transitive_closure(&self) -> Self {
+    let mut closure = self.clone();
+    let rows = closure.rows.as_slice_mut();
+    for k in 0..rows.len() {
+      // Every position in the row slice is a valid index.
+      let k_index = EnumIndex::<T>::from_usize(k).unwrap();
+      let (before, rest) = rows.split_at_mut(k);
+      let (row_k, after) = rest.split_first_mut().unwrap();
+      for row in before.iter_mut().chain(after.iter_mut()) {
+        if row.contains_index(k_index) {
+          row.union_with(row_k);
+        }
+      }
+    }
+    closure
+  }
+
+  /// Returns the smallest reflexive and transitive relation containing
+  /// `self`.
+  pub fn reflexive_transitive_closure(&self) -> Self {
+    self.transitive_closure().reflexive_closure()
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> Clone for EnumRelation<A, B, BitsetWord>
+{
+  fn clone(&self) -> Self {
+    EnumRelation {
+      rows: EnumMap::new_with(|a: A| self.rows[a].duplicate()),
+    }
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> Copy for EnumRelation<A, B, BitsetWord>
+where
+  A::TotalArray: Copy,
+  EnumSet<B, BitsetWord>: Copy,
+{
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>> + Debug,
+  B: EnumSetHelper<BitsetWord> + Debug,
+  BitsetWord: BitsetWordTrait,
+> Debug for EnumRelation<A, B, BitsetWord>
+{
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt.debug_set().entries(self.iter()).finish()
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> Default for EnumRelation<A, B, BitsetWord>
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> PartialEq for EnumRelation<A, B, BitsetWord>
+{
+  fn eq(&self, other: &Self) -> bool {
+    self.rows == other.rows
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> Eq for EnumRelation<A, B, BitsetWord>
+{
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> Hash for EnumRelation<A, B, BitsetWord>
+{
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+    self.rows.hash(state);
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> From<EnumMap<A, EnumSet<B, BitsetWord>>> for EnumRelation<A, B, BitsetWord>
+{
+  fn from(rows: EnumMap<A, EnumSet<B, BitsetWord>>) -> Self {
+    EnumRelation { rows }
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> From<EnumRelation<A, B, BitsetWord>> for EnumMap<A, EnumSet<B, BitsetWord>>
+{
+  fn from(relation: EnumRelation<A, B, BitsetWord>) -> Self {
+    relation.rows
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> ops::BitOr for EnumRelation<A, B, BitsetWord>
+{
+  type Output = Self;
+
+  fn bitor(mut self, rhs: Self) -> Self {
+    self.union_with(&rhs);
+    self
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> ops::BitOrAssign for EnumRelation<A, B, BitsetWord>
+{
+  fn bitor_assign(&mut self, rhs: Self) {
+    self.union_with(&rhs);
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> ops::BitAnd for EnumRelation<A, B, BitsetWord>
+{
+  type Output = Self;
+
+  fn bitand(mut self, rhs: Self) -> Self {
+    self.intersect_with(&rhs);
+    self
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> ops::BitAndAssign for EnumRelation<A, B, BitsetWord>
+{
+  fn bitand_assign(&mut self, rhs: Self) {
+    self.intersect_with(&rhs);
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> iter::FromIterator<(A, B)> for EnumRelation<A, B, BitsetWord>
+{
+  fn from_iter<I: iter::IntoIterator<Item = (A, B)>>(iter: I) -> Self {
+    let mut relation = Self::new();
+    for (a, b) in iter {
+      relation.relate(a, b);
+    }
+    relation
+  }
+}
+
+impl<
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> iter::Extend<(A, B)> for EnumRelation<A, B, BitsetWord>
+{
+  fn extend<I: iter::IntoIterator<Item = (A, B)>>(&mut self, iter: I) {
+    for (a, b) in iter {
+      self.relate(a, b);
+    }
+  }
+}
+
+impl<
+  'a,
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> iter::IntoIterator for &'a EnumRelation<A, B, BitsetWord>
+{
+  type Item = (A, B);
+  type IntoIter = EnumRelationIter<'a, A, B, BitsetWord>;
+
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+/// An iterator over the indices of the related pairs of a relation, in row
+/// order.
+///
+/// `iter` walks the members of the row at index `row`, and becomes `None`
+/// once the last row has been exhausted.
+pub struct EnumRelationIndexIter<
+  'a,
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> {
+  rows: &'a EnumMap<A, EnumSet<B, BitsetWord>>,
+  row: EnumIndex<A>,
+  iter: Option<EnumSetIndexIter<&'a B::BitsetArray, B, BitsetWord>>,
+}
+
+impl<
+  'a,
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> Iterator for EnumRelationIndexIter<'a, A, B, BitsetWord>
+{
+  type Item = (EnumIndex<A>, EnumIndex<B>);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if let Some(b) = self.iter.as_mut()?.next() {
+        return Some((self.row, b));
+      }
+      match self.row.next() {
+        Some(row) => {
+          self.row = row;
+          self.iter = Some(self.rows[row].iter_index());
+        }
+        None => self.iter = None,
+      }
+    }
+  }
+}
+
+impl<
+  'a,
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> iter::FusedIterator for EnumRelationIndexIter<'a, A, B, BitsetWord>
+{
+}
+
+/// An iterator over the related pairs of a relation, in row order.
+pub struct EnumRelationIter<
+  'a,
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> {
+  iter: EnumRelationIndexIter<'a, A, B, BitsetWord>,
+}
+
+impl<
+  'a,
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> Iterator for EnumRelationIter<'a, A, B, BitsetWord>
+{
+  type Item = (A, B);
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self
+      .iter
+      .next()
+      .map(|(a, b)| (a.into_value(), b.into_value()))
+  }
+}
+
+impl<
+  'a,
+  A: EnumArrayHelper<EnumSet<B, BitsetWord>>,
+  B: EnumSetHelper<BitsetWord>,
+  BitsetWord: BitsetWordTrait,
+> iter::FusedIterator for EnumRelationIter<'a, A, B, BitsetWord>
+{
+}
@@ -6,10 +6,10 @@ use crate::set::EnumSet;
 use crate::sub_base::BitsetWordTrait;
 use crate::sub_base::RawSizeWord;
 use crate::vec::EnumVec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker;
 use serde::{de, ser};
-use std::convert::TryFrom;
-use std::fmt;
-use std::marker;
 
 struct OptMapSerdeVisitor<
   T: EnumArrayHelper<V> + EnumSetHelper<BitsetWord>,
@@ -2,12 +2,12 @@ use crate::EnumIndex;
 use crate::base::EnumSetHelper;
 use crate::sub_base::BitsetWordTrait;
 use crate::sub_base::RawSizeWord;
-use std::borrow::Borrow;
-use std::fmt;
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::iter;
-use std::ops::Index;
+use core::borrow::Borrow;
+use core::fmt;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::iter;
+use core::ops::Index;
 
 /// A set of enumoid `T`'s members.
 ///
@@ -59,6 +59,9 @@ impl<T: EnumSetHelper<BitsetWord>, BitsetWord: BitsetWordTrait>
   }
 
   /// Sets whether a member is in the set.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn set(&mut self, key: T, flag: bool) {
     self.set_by_index(key.into(), flag)
@@ -73,11 +76,21 @@ impl<T: EnumSetHelper<BitsetWord>, BitsetWord: BitsetWordTrait>
   }
 
   /// Adds a member to the set and returns true if it was already present.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn insert(&mut self, key: T) -> bool {
     self.insert_by_index(key.into())
   }
 
+  /// Adds a member to the set and returns true if it was already present, or
+  /// returns `None` if the key is skipped.
+  #[inline]
+  pub fn try_insert(&mut self, key: T) -> Option<bool> {
+    EnumIndex::try_from_value(key).map(|index| self.insert_by_index(index))
+  }
+
   /// Removes a member index from the set and returns true if it was present.
   #[inline]
   pub fn remove_by_index(&mut self, index: EnumIndex<T>) -> bool {
@@ -87,11 +100,21 @@ impl<T: EnumSetHelper<BitsetWord>, BitsetWord: BitsetWordTrait>
   }
 
   /// Removes a member from the set and returns true if it was present.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn remove(&mut self, key: T) -> bool {
     self.remove_by_index(key.into())
   }
 
+  /// Removes a member from the set and returns true if it was present, or
+  /// returns `None` if the key is skipped.
+  #[inline]
+  pub fn try_remove(&mut self, key: T) -> Option<bool> {
+    EnumIndex::try_from_value(key).map(|index| self.remove_by_index(index))
+  }
+
   /// Clears all the members from the set.
   pub fn clear(&mut self) {
     self.data = T::DEFAULT_BITSET;
@@ -109,11 +132,21 @@ impl<T: EnumSetHelper<BitsetWord>, BitsetWord: BitsetWordTrait>
   }
 
   /// Returns true if a specific member is in the set.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn contains(&self, key: T) -> bool {
     self.contains_index(key.into())
   }
 
+  /// Returns true if a specific member is in the set, or `None` if the key is
+  /// skipped.
+  #[inline]
+  pub fn try_contains(&self, key: T) -> Option<bool> {
+    EnumIndex::try_from_value(key).map(|index| self.contains_index(index))
+  }
+
   /// Returns an iterator over the indices of the members of the set.
   #[inline]
   pub fn iter_index(&self) -> EnumSetIndexIter<&T::BitsetArray, T, BitsetWord> {
@@ -140,6 +173,52 @@ impl<T: EnumSetHelper<BitsetWord>, BitsetWord: BitsetWordTrait>
     slice.iter().any(|&val| val != T::BitsetWord::ZERO)
   }
 
+  /// Returns the set of members in `self` but not in `other`.
+  pub(crate) fn difference(&self, other: &Self) -> Self {
+    let mut set = self.duplicate();
+    for (a, &b) in T::slice_bitset_mut(&mut set.data)
+      .iter_mut()
+      .zip(T::slice_bitset(&other.data))
+    {
+      *a = *a & !b;
+    }
+    set
+  }
+
+  /// Returns true if every member of `self` is also in `other`.
+  pub(crate) fn is_subset(&self, other: &Self) -> bool {
+    T::slice_bitset(&self.data)
+      .iter()
+      .zip(T::slice_bitset(&other.data))
+      .all(|(&a, &b)| a & !b == T::BitsetWord::ZERO)
+  }
+
+  /// Copies the set without the `Clone` bounds which its derived impl places
+  /// on `T`.
+  pub(crate) fn duplicate(&self) -> Self {
+    let mut data = T::DEFAULT_BITSET;
+    T::slice_bitset_mut(&mut data).copy_from_slice(T::slice_bitset(&self.data));
+    EnumSet { data }
+  }
+
+  pub(crate) fn union_with(&mut self, other: &Self) {
+    for (a, &b) in T::slice_bitset_mut(&mut self.data)
+      .iter_mut()
+      .zip(T::slice_bitset(&other.data))
+    {
+      *a = *a | b;
+    }
+  }
+
+  pub(crate) fn intersect_with(&mut self, other: &Self) {
+    for (a, &b) in T::slice_bitset_mut(&mut self.data)
+      .iter_mut()
+      .zip(T::slice_bitset(&other.data))
+    {
+      *a = *a & b;
+    }
+  }
+
   /// Returns true if all possible members are in the set.
   pub fn all(&self) -> bool {
     let slice = T::slice_bitset(&self.data);
@@ -187,7 +266,7 @@ impl<T: EnumSetHelper<BitsetWord>, BitsetWord: BitsetWordTrait> Eq
 impl<T: EnumSetHelper<BitsetWord>, BitsetWord: BitsetWordTrait> Hash
   for EnumSet<T, BitsetWord>
 {
-  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
     T::slice_bitset(&self.data).hash(state);
   }
 }
@@ -200,6 +279,8 @@ impl<T: EnumSetHelper<BitsetWord>, BitsetWord: BitsetWordTrait> Index<T>
 {
   type Output = bool;
 
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   fn index(&self, i: T) -> &bool {
     if self.contains(i) { TRUE } else { FALSE }
@@ -1,8 +1,29 @@
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::ops::{BitAnd, BitOr, Not, Shl, Shr, Sub};
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ops::{BitAnd, BitOr, Not, Shl, Shr, Sub};
 
-pub trait RawSizeWord: Copy + Debug + Eq + Ord + Hash {
+mod sealed {
+  /// Prevents other crates from implementing the public word traits, which
+  /// are only meant for the primitive integer types.
+  pub trait Sealed {}
+
+  macro_rules! impl_sealed {
+    ($($t: ty),*) => {
+      $(impl Sealed for $t {})*
+    };
+  }
+
+  impl_sealed!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+  );
+}
+
+/// Index word types of Enumoids.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait RawSizeWord: sealed::Sealed + Copy + Debug + Eq + Ord + Hash {
+  /// The next wider word, or `usize` for `u32` and `usize`.
+  type Wider: RawSizeWord;
   const ZERO: Self;
   fn inc(self) -> Self;
   fn dec(self) -> Self;
@@ -11,8 +32,9 @@ pub trait RawSizeWord: Copy + Debug + Eq + Ord + Hash {
 }
 
 macro_rules! impl_size_word {
-  ($t: ty) => {
+  ($t: ty, $wider: ty) => {
     impl RawSizeWord for $t {
+      type Wider = $wider;
       const ZERO: Self = 0;
       #[inline(always)]
       fn inc(self) -> Self {
@@ -34,10 +56,10 @@ macro_rules! impl_size_word {
   };
 }
 
-impl_size_word!(u8);
-impl_size_word!(u16);
-impl_size_word!(u32);
-impl_size_word!(usize);
+impl_size_word!(u8, u16);
+impl_size_word!(u16, u32);
+impl_size_word!(u32, usize);
+impl_size_word!(usize, usize);
 
 pub trait BitsetWordTrait:
   Copy
@@ -79,3 +101,76 @@ impl_bitset_word_trait!(u16);
 impl_bitset_word_trait!(u32);
 impl_bitset_word_trait!(u64);
 impl_bitset_word_trait!(usize);
+
+/// Element types for the checked and saturating arithmetic of `EnumMap`,
+/// implemented for the primitive integer types.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait CheckedArithTrait: sealed::Sealed + Copy {
+  fn checked_add(self, rhs: Self) -> Option<Self>;
+  fn checked_sub(self, rhs: Self) -> Option<Self>;
+  fn saturating_add(self, rhs: Self) -> Self;
+  fn saturating_sub(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_checked_arith_trait {
+  ($t: ty) => {
+    impl CheckedArithTrait for $t {
+      #[inline(always)]
+      fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+      }
+      #[inline(always)]
+      fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+      }
+      #[inline(always)]
+      fn saturating_add(self, rhs: Self) -> Self {
+        self.saturating_add(rhs)
+      }
+      #[inline(always)]
+      fn saturating_sub(self, rhs: Self) -> Self {
+        self.saturating_sub(rhs)
+      }
+    }
+  };
+}
+
+impl_checked_arith_trait!(u8);
+impl_checked_arith_trait!(u16);
+impl_checked_arith_trait!(u32);
+impl_checked_arith_trait!(u64);
+impl_checked_arith_trait!(u128);
+impl_checked_arith_trait!(usize);
+impl_checked_arith_trait!(i8);
+impl_checked_arith_trait!(i16);
+impl_checked_arith_trait!(i32);
+impl_checked_arith_trait!(i64);
+impl_checked_arith_trait!(i128);
+impl_checked_arith_trait!(isize);
+
+/// Count types for `EnumBag`, implemented for the unsigned integer types.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait BagCountTrait:
+  sealed::Sealed + CheckedArithTrait + Debug + Default + Eq + Ord + Hash
+{
+  const ZERO: Self;
+  const ONE: Self;
+}
+
+macro_rules! impl_bag_count_trait {
+  ($t: ty) => {
+    impl BagCountTrait for $t {
+      const ZERO: Self = 0;
+      const ONE: Self = 1;
+    }
+  };
+}
+
+impl_bag_count_trait!(u8);
+impl_bag_count_trait!(u16);
+impl_bag_count_trait!(u32);
+impl_bag_count_trait!(u64);
+impl_bag_count_trait!(u128);
+impl_bag_count_trait!(usize);
@@ -6,14 +6,16 @@ use crate::iter::EnumSliceIter;
 use crate::iter::EnumSliceIterMut;
 use crate::opt_map::EnumOptionMap;
 use crate::sub_base::RawSizeWord;
-use std::convert::TryFrom;
-use std::fmt;
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::iter;
-use std::mem;
-use std::ops::{Index, IndexMut};
-use std::ptr;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::iter;
+use core::mem;
+use core::ops::{Index, IndexMut};
+use core::ptr;
 
 /// A vector of values `V` indexed by enumoid `T`.
 pub struct EnumVec<T: EnumArrayHelper<V>, V> {
@@ -30,6 +32,20 @@ impl<T: EnumArrayHelper<V>, V> EnumVec<T, V> {
     }
   }
 
+  /// Creates a new vector with no elements on the heap without building it on
+  /// the stack.
+  #[cfg(feature = "alloc")]
+  pub fn new_boxed() -> Box<Self> {
+    let mut boxed = Box::<Self>::new_uninit();
+    let ptr = boxed.as_mut_ptr();
+    // The data array is valid while uninitialised, so only the length needs
+    // to be written.
+    unsafe {
+      (&raw mut (*ptr).len).write(T::Word::ZERO);
+      boxed.assume_init()
+    }
+  }
+
   /// Creates a new vector with a specified number of elements generated by a
   /// callback function.
   pub fn new_with<F>(size: EnumSize<T>, mut f: F) -> Self
@@ -37,7 +53,7 @@ impl<T: EnumArrayHelper<V>, V> EnumVec<T, V> {
     F: FnMut(T) -> V,
   {
     let mut vec = Self::new();
-    for key in size.iter() {
+    for key in size.values() {
       let _ = vec.try_push(f(key));
     }
     vec
@@ -55,7 +71,7 @@ impl<T: EnumArrayHelper<V>, V> EnumVec<T, V> {
       );
       let inited =
         T::partial_slice(&self.data).get_unchecked(0..self.len.as_());
-      &*(inited as *const [std::mem::MaybeUninit<V>] as *const [V])
+      &*(inited as *const [core::mem::MaybeUninit<V>] as *const [V])
     }
   }
 
@@ -71,7 +87,7 @@ impl<T: EnumArrayHelper<V>, V> EnumVec<T, V> {
       );
       let inited = T::partial_slice_mut(&mut self.data)
         .get_unchecked_mut(0..self.len.as_());
-      &mut *(inited as *mut [std::mem::MaybeUninit<V>] as *mut [V])
+      &mut *(inited as *mut [core::mem::MaybeUninit<V>] as *mut [V])
     }
   }
 
@@ -84,11 +100,21 @@ impl<T: EnumArrayHelper<V>, V> EnumVec<T, V> {
 
   /// Returns a reference to the value associated with a given key,
   /// or `None` if the key is beyond the end of the vector.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn get(&self, key: T) -> Option<&V> {
     self.get_by_index(key.into())
   }
 
+  /// Returns a reference to the value associated with a given key, or `None`
+  /// if the key is skipped or beyond the end of the vector.
+  #[inline]
+  pub fn try_get(&self, key: T) -> Option<&V> {
+    self.get_by_index(EnumIndex::try_from_value(key)?)
+  }
+
   /// Returns a mutable reference to the value associated with a given index,
   /// or `None` if the index is beyond the end of the vector.
   #[inline]
@@ -98,11 +124,21 @@ impl<T: EnumArrayHelper<V>, V> EnumVec<T, V> {
 
   /// Returns a mutable reference to the value associated with a given key,
   /// or `None` if the key is beyond the end of the vector.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn get_mut(&mut self, key: T) -> Option<&mut V> {
     self.get_by_index_mut(key.into())
   }
 
+  /// Returns a mutable reference to the value associated with a given key, or
+  /// `None` if the key is skipped or beyond the end of the vector.
+  #[inline]
+  pub fn try_get_mut(&mut self, key: T) -> Option<&mut V> {
+    self.get_by_index_mut(EnumIndex::try_from_value(key)?)
+  }
+
   /// Returns true if the vector is empty.
   #[inline]
   pub fn is_empty(&self) -> bool {
@@ -122,6 +158,9 @@ impl<T: EnumArrayHelper<V>, V> EnumVec<T, V> {
   }
 
   /// Returns true if the vector contains the key.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn contains(&self, value: T) -> bool {
     value.into_word() < self.len
@@ -146,6 +185,7 @@ impl<T: EnumArrayHelper<V>, V> EnumVec<T, V> {
   ///
   /// # Panics
   /// Panics if `a` or `b` are beyond the end of the vector.
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   pub fn swap(&mut self, a: T, b: T) {
     self.swap_by_index(a.into(), b.into())
@@ -170,6 +210,9 @@ impl<T: EnumArrayHelper<V>, V> EnumVec<T, V> {
   }
 
   /// Removes an element and returns it, replacing it with the last element.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   pub fn swap_remove(&mut self, key: T) -> Option<V> {
     self.swap_remove_at_index(key.into())
   }
@@ -193,6 +236,9 @@ impl<T: EnumArrayHelper<V>, V> EnumVec<T, V> {
   }
 
   /// Removes an element and returns it, moving the following elements down.
+  ///
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   pub fn remove(&mut self, key: T) -> Option<V> {
     self.remove_at_index(key.into())
   }
@@ -290,7 +336,7 @@ impl<T: EnumArrayHelper<V>, V: PartialEq> PartialEq for EnumVec<T, V> {
 impl<T: EnumArrayHelper<V>, V: Eq> Eq for EnumVec<T, V> {}
 
 impl<T: EnumArrayHelper<V>, V: Hash> Hash for EnumVec<T, V> {
-  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
     self.as_slice().hash(state);
   }
 }
@@ -307,6 +353,8 @@ impl<T: EnumArrayHelper<V>, V> Index<EnumIndex<T>> for EnumVec<T, V> {
 impl<T: EnumArrayHelper<V>, V> Index<T> for EnumVec<T, V> {
   type Output = V;
 
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   fn index(&self, key: T) -> &V {
     &self[EnumIndex::from_value(key)]
@@ -321,6 +369,8 @@ impl<T: EnumArrayHelper<V>, V> IndexMut<EnumIndex<T>> for EnumVec<T, V> {
 }
 
 impl<T: EnumArrayHelper<V>, V> IndexMut<T> for EnumVec<T, V> {
+  /// # Panics
+  /// Panics on skipped values; see [`crate::Enumoid::into_word`].
   #[inline]
   fn index_mut(&mut self, key: T) -> &mut V {
     &mut self[EnumIndex::from_value(key)]
@@ -454,7 +504,7 @@ impl<T: EnumArrayHelper<V> + EnumSetHelper<u8>, V> TryFrom<EnumOptionMap<T, V>>
   fn try_from(from: EnumOptionMap<T, V>) -> Result<Self, Self::Error> {
     match from.is_vec() {
       Some(size) => Ok(EnumVec {
-        len: size.into_word(),
+        len: size.into_index_word(),
         data: from.into_partial(),
       }),
       None => Err(()),
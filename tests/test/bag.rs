@@ -0,0 +1,140 @@
+use crate::test::types::Three;
+use enumoid::BagCountTrait;
+use enumoid::EnumBag;
+use enumoid::EnumSet;
+
+fn bag(a: u32, b: u32, c: u32) -> EnumBag<Three, u32> {
+  let mut bag = EnumBag::new();
+  bag.set_count(Three::A, a);
+  bag.set_count(Three::B, b);
+  bag.set_count(Three::C, c);
+  bag
+}
+
+#[test]
+fn test_empty_state() {
+  let bag = EnumBag::<Three, u32>::new();
+
+  assert!(bag.is_empty(), "Expected new bag to be empty");
+  assert_eq!(bag.total(), 0, "Expected new bag to have total of 0");
+  assert_eq!(bag.count(Three::A), 0);
+  assert_eq!(bag.iter().count(), 0, "Expected no members");
+  assert_eq!(bag.support(), EnumSet::new());
+}
+
+#[test]
+fn test_add_and_count() {
+  let mut bag = EnumBag::<Three, u32>::new();
+
+  assert_eq!(bag.add(Three::B, 2), 2, "Expected new count");
+  assert_eq!(bag.add(Three::B, 3), 5, "Expected accumulated count");
+  assert_eq!(bag.count(Three::B), 5);
+  assert_eq!(bag[Three::B], 5, "Expected Index to return the count");
+  assert_eq!(bag.total(), 5);
+  assert!(!bag.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "EnumBag count overflowed")]
+fn test_add_overflow() {
+  let mut bag = EnumBag::<Three, u8>::new();
+  bag.add(Three::A, 200);
+  bag.add(Three::A, 100);
+}
+
+#[test]
+fn test_remove_saturating() {
+  let mut bag = bag(5, 1, 0);
+
+  assert_eq!(bag.remove(Three::A, 2), 2, "Expected full removal");
+  assert_eq!(bag.count(Three::A), 3);
+  assert_eq!(bag.remove(Three::B, 4), 1, "Expected saturated removal");
+  assert_eq!(bag.count(Three::B), 0);
+  assert_eq!(bag.remove(Three::C, 1), 0, "Expected nothing to remove");
+}
+
+#[test]
+fn test_try_remove() {
+  let mut bag = bag(5, 1, 0);
+
+  assert_eq!(bag.try_remove(Three::A, 5), Ok(()));
+  assert_eq!(bag.count(Three::A), 0);
+  assert_eq!(
+    bag.try_remove(Three::B, 2),
+    Err(1),
+    "Expected current count as the error"
+  );
+  assert_eq!(bag.count(Three::B), 1, "Expected count to be unchanged");
+}
+
+#[test]
+fn test_support_and_iter() {
+  let bag = bag(2, 0, 7);
+
+  let collected: Vec<_> = bag.iter().collect();
+  assert_eq!(collected, vec![(Three::A, 2), (Three::C, 7)]);
+  assert_eq!(bag.support(), [Three::A, Three::C].into_iter().collect());
+  assert_eq!(format!("{bag:?}"), "{A: 2, C: 7}");
+}
+
+#[test]
+fn test_multiset_operations() {
+  let x = bag(3, 1, 0);
+  let y = bag(1, 4, 2);
+
+  assert_eq!(x | y, bag(3, 4, 2), "Expected union to take maximums");
+  assert_eq!(
+    x & y,
+    bag(1, 1, 0),
+    "Expected intersection to take minimums"
+  );
+  assert_eq!(x + y, bag(4, 5, 2), "Expected sum to add counts");
+  assert_eq!(x - y, bag(2, 0, 0), "Expected difference to saturate");
+
+  let mut z = x;
+  z += y;
+  z -= bag(1, 1, 1);
+  z |= bag(0, 0, 5);
+  z &= bag(9, 9, 4);
+  assert_eq!(z, bag(3, 4, 4));
+}
+
+#[test]
+fn test_is_sub_bag() {
+  assert!(bag(1, 0, 2).is_sub_bag(&bag(1, 3, 2)));
+  assert!(!bag(2, 0, 0).is_sub_bag(&bag(1, 3, 2)));
+  assert!(EnumBag::new().is_sub_bag(&bag(0, 0, 0)));
+}
+
+#[test]
+fn test_most_common() {
+  let bag = bag(2, 5, 2);
+
+  assert_eq!(
+    bag.most_common().as_slice(),
+    &[Three::B, Three::A, Three::C],
+    "Expected descending counts with ties in index order"
+  );
+  assert!(EnumBag::<Three, u32>::new().most_common().is_empty());
+}
+
+#[test]
+fn test_from_iterator() {
+  let bag: EnumBag<Three> =
+    [Three::A, Three::C, Three::A].into_iter().collect();
+
+  assert_eq!(bag.count(Three::A), 2);
+  assert_eq!(bag.count(Three::B), 0);
+  assert_eq!(bag.count(Three::C), 1);
+  assert_eq!(bag.total(), 3);
+}
+
+fn total_of<N: BagCountTrait>(bag: &EnumBag<Three, N>) -> N {
+  bag.total()
+}
+
+#[test]
+fn test_generic_count_type() {
+  assert_eq!(total_of(&bag(1, 2, 3)), 6);
+  assert_eq!(total_of(&EnumBag::<Three, u8>::new()), 0);
+}
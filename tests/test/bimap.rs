@@ -0,0 +1,80 @@
+use crate::test::types::{Sixteen, Three};
+use enumoid::EnumBiMap;
+
+#[test]
+fn test_empty_state() {
+  let map = EnumBiMap::<Three, Sixteen>::new();
+
+  assert!(map.is_empty(), "Expected new map to be empty");
+  assert_eq!(map.count(), 0);
+  assert_eq!(map.get_by_left(Three::A), None);
+  assert_eq!(map.get_by_right(Sixteen::A), None);
+}
+
+#[test]
+fn test_insert_and_get() {
+  let mut map = EnumBiMap::<Three, Sixteen>::new();
+
+  assert_eq!(map.insert(Three::A, Sixteen::P), (None, None));
+  assert_eq!(map.insert(Three::C, Sixteen::B), (None, None));
+  assert_eq!(map.get_by_left(Three::A), Some(Sixteen::P));
+  assert_eq!(map.get_by_right(Sixteen::B), Some(Three::C));
+  assert!(map.contains_left(Three::C));
+  assert!(!map.contains_right(Sixteen::A));
+  assert_eq!(map.count(), 2);
+}
+
+#[test]
+fn test_insert_displaces_pairs() {
+  let mut map = EnumBiMap::<Three, Sixteen>::new();
+  map.insert(Three::A, Sixteen::A);
+  map.insert(Three::B, Sixteen::B);
+
+  assert_eq!(
+    map.insert(Three::A, Sixteen::B),
+    (Some((Three::A, Sixteen::A)), Some((Three::B, Sixteen::B))),
+    "Expected both old pairs to be displaced"
+  );
+  assert_eq!(map.count(), 1);
+  assert_eq!(map.get_by_right(Sixteen::A), None);
+  assert_eq!(map.get_by_left(Three::B), None);
+
+  assert_eq!(
+    map.insert(Three::A, Sixteen::B),
+    (Some((Three::A, Sixteen::B)), None),
+    "Expected an existing pair to be returned once"
+  );
+}
+
+#[test]
+fn test_remove() {
+  let mut map: EnumBiMap<Three, Sixteen> =
+    [(Three::A, Sixteen::C), (Three::B, Sixteen::D)]
+      .into_iter()
+      .collect();
+
+  assert_eq!(map.remove_by_left(Three::A), Some((Three::A, Sixteen::C)));
+  assert_eq!(map.get_by_right(Sixteen::C), None);
+  assert_eq!(
+    map.remove_by_right(Sixteen::D),
+    Some((Three::B, Sixteen::D))
+  );
+  assert_eq!(map.remove_by_right(Sixteen::D), None);
+  assert!(map.is_empty());
+}
+
+#[test]
+fn test_iteration() {
+  let mut map = EnumBiMap::<Three, Sixteen>::new();
+  map.insert(Three::C, Sixteen::A);
+  map.insert(Three::A, Sixteen::O);
+
+  let collected: Vec<_> = map.iter().collect();
+  assert_eq!(
+    collected,
+    vec![(Three::A, Sixteen::O), (Three::C, Sixteen::A)],
+    "Expected pairs in order of their left values"
+  );
+  assert_eq!(format!("{map:?}"), "{A: O, C: A}");
+  assert_eq!(map.clone(), map);
+}
@@ -0,0 +1,136 @@
+use crate::test::drop_tracker::DropTracker;
+use crate::test::types::{Seventeen, Three};
+use enumoid::EnumDeque;
+use std::cell::Cell;
+
+fn values<T: enumoid::EnumArrayHelper<u16>>(
+  deque: &EnumDeque<T, u16>,
+) -> Vec<u16> {
+  deque.iter().map(|(_, &value)| value).collect()
+}
+
+#[test]
+fn test_empty_state() {
+  let mut deque = EnumDeque::<Three, u16>::new();
+
+  assert!(deque.is_empty(), "Expected new deque to be empty");
+  assert!(!deque.is_full());
+  assert_eq!(deque.size().into_usize(), 0);
+  assert_eq!(deque.front(), None);
+  assert_eq!(deque.back(), None);
+  assert_eq!(deque.pop_front(), None);
+  assert_eq!(deque.pop_back(), None);
+  assert_eq!(deque.as_slices(), (&[][..], &[][..]));
+}
+
+#[test]
+fn test_push_and_pop_both_ends() {
+  let mut deque = EnumDeque::<Three, u16>::new();
+
+  assert_eq!(deque.try_push_back(2), Ok(()));
+  assert_eq!(deque.try_push_front(1), Ok(()));
+  assert_eq!(deque.try_push_back(3), Ok(()));
+  assert!(deque.is_full());
+  assert_eq!(deque.try_push_front(0), Err(0), "Expected capacity error");
+  assert_eq!(deque.try_push_back(4), Err(4), "Expected capacity error");
+  assert_eq!(values(&deque), vec![1, 2, 3]);
+  assert_eq!(deque.front(), Some(&1));
+  assert_eq!(deque.back(), Some(&3));
+  assert_eq!(deque.get(Three::B), Some(&2));
+  assert_eq!(format!("{deque:?}"), "{A: 1, B: 2, C: 3}");
+
+  assert_eq!(deque.pop_back(), Some(3));
+  assert_eq!(deque.pop_front(), Some(1));
+  assert_eq!(deque.get(Three::B), None);
+  *deque.front_mut().unwrap() = 7;
+  assert_eq!(deque.back(), Some(&7));
+  assert_eq!(deque.pop_front(), Some(7));
+  assert!(deque.is_empty());
+}
+
+#[test]
+fn test_wrapping_slices() {
+  let mut deque = EnumDeque::<Seventeen, u16>::new();
+  for i in 0..17 {
+    deque.try_push_back(i).unwrap();
+  }
+  for _ in 0..5 {
+    deque.pop_front();
+  }
+  for i in 17..20 {
+    deque.try_push_back(i).unwrap();
+  }
+
+  let (front, back) = deque.as_slices();
+  assert_eq!(front, &(5..17).collect::<Vec<_>>()[..]);
+  assert_eq!(back, &[17, 18, 19], "Expected the wrapped run");
+  assert_eq!(values(&deque), (5..20).collect::<Vec<_>>());
+
+  let (front, back) = deque.as_slices_mut();
+  front[0] = 100;
+  back[2] = 200;
+  assert_eq!(deque.front(), Some(&100));
+  assert_eq!(deque.back(), Some(&200));
+
+  let contiguous = deque.make_contiguous();
+  assert_eq!(contiguous.len(), 15);
+  assert_eq!(contiguous[0], 100);
+  assert_eq!(contiguous[14], 200);
+  assert_eq!(deque.as_slices().1, &[], "Expected a single run");
+  assert_eq!(deque.get(Seventeen::B), Some(&6));
+}
+
+#[test]
+fn test_push_front_wraps() {
+  let mut deque = EnumDeque::<Three, u16>::new();
+  deque.try_push_front(3).unwrap();
+  deque.try_push_front(2).unwrap();
+
+  assert_eq!(values(&deque), vec![2, 3]);
+  assert_eq!(deque.as_slices(), (&[2, 3][..], &[][..]));
+  assert_eq!(deque.make_contiguous(), &[2, 3]);
+}
+
+#[test]
+fn test_push_back_overwriting() {
+  let mut deque = EnumDeque::<Three, u16>::new();
+
+  for i in 0..3 {
+    assert_eq!(deque.push_back_overwriting(i), None);
+  }
+  assert_eq!(deque.push_back_overwriting(3), Some(0), "Expected eviction");
+  assert_eq!(deque.push_back_overwriting(4), Some(1));
+  assert_eq!(values(&deque), vec![2, 3, 4]);
+}
+
+#[test]
+fn test_equality_ignores_layout() {
+  let mut a = EnumDeque::<Three, u16>::new();
+  a.try_push_back(1).unwrap();
+  a.try_push_back(2).unwrap();
+  let mut b = EnumDeque::<Three, u16>::new();
+  b.try_push_back(2).unwrap();
+  b.try_push_front(1).unwrap();
+
+  assert_eq!(a, b);
+  assert_eq!(a.clone(), b);
+  b.pop_back();
+  assert_ne!(a, b);
+}
+
+#[test]
+fn test_drop_wrapped_elements() {
+  let drops = Cell::new(0);
+  {
+    let mut deque = EnumDeque::<Three, DropTracker>::new();
+    deque.try_push_back(DropTracker::new(1, &drops)).ok();
+    deque.try_push_back(DropTracker::new(2, &drops)).ok();
+    deque.try_push_back(DropTracker::new(3, &drops)).ok();
+    let evicted = deque.push_back_overwriting(DropTracker::new(4, &drops));
+    assert_eq!(evicted.map(|x| x.id()), Some(1));
+    assert_eq!(drops.get(), 1);
+    assert_eq!(deque.pop_back().map(|x| x.id()), Some(4));
+    assert_eq!(drops.get(), 2);
+  }
+  assert_eq!(drops.get(), 4, "Expected every element to be dropped once");
+}
@@ -0,0 +1,75 @@
+use crate::test::types::{Flag, Opcode, ReprSparse, ReprThree};
+use enumoid::{DiscriminantError, EnumDiscriminant, EnumMap, Enumoid};
+
+#[test]
+fn test_explicit_discriminants() {
+  assert_eq!(Opcode::Nop.discriminant(), 0x90);
+  assert_eq!(isize::from(Opcode::Int3), 0xCC);
+  assert_eq!(Opcode::try_from(0xE9), Ok(Opcode::Jmp));
+  assert_eq!(Opcode::try_from(0), Err(DiscriminantError::new(0)));
+
+  // Indices remain dense and in declaration order.
+  assert_eq!(Opcode::Nop.into_word(), 0);
+  assert_eq!(Opcode::Jmp.into_word(), 1);
+  assert_eq!(Opcode::Int3.into_word(), 2);
+}
+
+#[test]
+fn test_repr_discriminants() {
+  assert_eq!(ReprSparse::A.discriminant(), 10u16);
+  assert_eq!(u16::from(ReprSparse::C), 5);
+  assert_eq!(ReprSparse::try_from(20u16), Ok(ReprSparse::B));
+  assert_eq!(
+    ReprSparse::try_from(0u16),
+    Err(DiscriminantError::new(0u16))
+  );
+
+  assert_eq!(ReprThree::C.discriminant(), 2u8);
+  assert_eq!(ReprThree::from_discriminant(1u8), Ok(ReprThree::B));
+  assert_eq!(
+    ReprThree::from_discriminant(3u8).map_err(DiscriminantError::into_value),
+    Err(3)
+  );
+}
+
+#[test]
+fn test_discriminant_error() {
+  let error = Opcode::try_from(0x42).unwrap_err();
+  assert_eq!(
+    error.to_string(),
+    "66 is not the discriminant of any variant"
+  );
+  let error: Box<dyn std::error::Error> = Box::new(error);
+  assert!(error.source().is_none());
+}
+
+#[test]
+fn test_generic_discriminant() {
+  fn discriminants<T: EnumDiscriminant>() -> Vec<T::Repr> {
+    T::iter().map(Enumoid::discriminant).collect()
+  }
+  assert_eq!(discriminants::<Opcode>(), vec![0x90, 0xE9, 0xCC]);
+  assert_eq!(discriminants::<ReprSparse>(), vec![10, 20, 5]);
+}
+
+#[test]
+fn test_without_opt_in() {
+  // The user's own conversions and inherent method are used.
+  assert_eq!(u8::from(Flag::On), 2);
+  assert_eq!(Flag::Off.discriminant(), "off");
+  assert_eq!(Flag::On.into_word(), 1);
+}
+
+#[test]
+fn test_decode_into_map() {
+  let bytes: [u8; 5] = [0x90, 0xE9, 0x90, 0x00, 0xCC];
+  let mut counts = EnumMap::<Opcode, usize>::new();
+  for byte in bytes {
+    if let Ok(op) = Opcode::try_from(byte as isize) {
+      counts[op] += 1;
+    }
+  }
+  assert_eq!(counts[Opcode::Nop], 2);
+  assert_eq!(counts[Opcode::Jmp], 1);
+  assert_eq!(counts[Opcode::Int3], 1);
+}
@@ -0,0 +1,74 @@
+use crate::test::types::{Seventeen, Three};
+use enumoid::EnumDisjointSets;
+use enumoid::EnumSet;
+
+#[test]
+fn test_initial_state() {
+  let mut sets = EnumDisjointSets::<Three>::new();
+
+  assert_eq!(sets.num_classes(), 3, "Expected one class per member");
+  assert!(!sets.same_set(Three::A, Three::B));
+  assert_eq!(sets.find(Three::C), Three::C);
+  assert_eq!(sets.class_of(Three::B), [Three::B].into_iter().collect());
+  assert_eq!(format!("{sets:?}"), "[{A}, {B}, {C}]");
+}
+
+#[test]
+fn test_union() {
+  let mut sets = EnumDisjointSets::<Three>::new();
+
+  assert!(sets.union(Three::A, Three::C), "Expected separate classes");
+  assert!(!sets.union(Three::C, Three::A), "Expected already merged");
+  assert_eq!(sets.num_classes(), 2);
+  assert!(sets.same_set(Three::A, Three::C));
+  assert!(!sets.same_set(Three::A, Three::B));
+  assert_eq!(sets.find(Three::A), sets.find(Three::C));
+  assert_eq!(sets.representative(Three::A), sets.representative(Three::C));
+  assert_eq!(
+    sets.class_of(Three::C),
+    [Three::A, Three::C].into_iter().collect()
+  );
+}
+
+#[test]
+fn test_classes() {
+  use Seventeen::*;
+  let mut sets = EnumDisjointSets::<Seventeen>::new();
+  for &(a, b) in &[(Q, B), (B, D), (E, F), (P, E), (A, Q)] {
+    sets.union(a, b);
+  }
+
+  assert_eq!(sets.num_classes(), 17 - 5);
+  let classes: Vec<EnumSet<Seventeen>> = sets.classes().collect();
+  assert_eq!(classes.len(), sets.num_classes());
+  assert_eq!(classes[0], [A, B, D, Q].into_iter().collect());
+  assert_eq!(classes[1], [C].into_iter().collect());
+  assert_eq!(classes[2], [E, F, P].into_iter().collect());
+  assert_eq!(
+    classes.iter().map(EnumSet::count).sum::<usize>(),
+    17,
+    "Expected every member in exactly one class"
+  );
+}
+
+#[test]
+fn test_copy_snapshot() {
+  let mut sets = EnumDisjointSets::<Three>::new();
+  sets.union(Three::A, Three::B);
+  let snapshot = sets;
+  sets.union(Three::B, Three::C);
+
+  assert_eq!(sets.num_classes(), 1);
+  assert_eq!(
+    snapshot.num_classes(),
+    2,
+    "Expected snapshot to be unchanged"
+  );
+  assert_eq!(
+    snapshot.class_of(Three::C),
+    [Three::C].into_iter().collect()
+  );
+
+  sets.clear();
+  assert_eq!(sets.num_classes(), 3);
+}
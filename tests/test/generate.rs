@@ -0,0 +1,61 @@
+use crate::test::types::{
+  Channel, Colour, Escaped, QuotedSku, Sensor, Sku, ThreeHundred,
+};
+use enumoid::Enumoid;
+
+#[test]
+fn test_number() {
+  assert_eq!(Channel::SIZE, 7);
+  assert_eq!(
+    Channel::iter().map(Channel::number).collect::<Vec<_>>(),
+    vec![0, 10, 20, 99, 1, 2, 3]
+  );
+  const NUMBER: u64 = Channel::Ch099Hz.number();
+  assert_eq!(NUMBER, 99);
+}
+
+#[test]
+fn test_repr_cast() {
+  assert_eq!(std::mem::size_of::<Channel>(), 1);
+  assert_eq!(Channel::Ch099Hz as u8, Channel::Ch099Hz.into_word());
+  assert_eq!(std::mem::size_of::<ThreeHundred>(), 2);
+}
+
+#[test]
+fn test_from_text_file() {
+  assert_eq!(Sensor::SIZE, 3);
+  assert_eq!(Sensor::NAMES, ["Intake", "Exhaust", "Coolant"]);
+  assert_eq!(Sensor::Coolant.name(), "Coolant");
+  assert_eq!(Sensor::from_name("Exhaust"), Some(Sensor::Exhaust));
+  assert_eq!(Sensor::from_name("Engine sensors"), None);
+}
+
+#[test]
+fn test_from_csv_file() {
+  assert_eq!(Sku::NAMES, ["Widget01", "Gadget02"]);
+  assert_eq!(Sku::from_name("sku"), None);
+  assert_eq!(Sku::LAST, Sku::Gadget02);
+}
+
+#[test]
+fn test_from_json_file() {
+  assert_eq!(
+    Colour::iter().map(Colour::name).collect::<Vec<_>>(),
+    vec!["Red", "Green", "Blue"]
+  );
+  assert_eq!(Colour::from_name("Blue"), Some(Colour::Blue));
+}
+
+#[test]
+fn test_from_json_file_escapes() {
+  assert_eq!(Escaped::NAMES, ["Caf\u{e9}", "Amber", "Lime"]);
+  assert_eq!(Escaped::from_name("Caf\u{e9}"), Some(Escaped::Café));
+  assert_eq!(Escaped::Lime.name(), "Lime");
+}
+
+#[test]
+fn test_from_csv_file_quoted() {
+  assert_eq!(QuotedSku::NAMES, ["Widget01", "Gadget02", "Gizmo03"]);
+  assert_eq!(QuotedSku::from_name("code"), None);
+  assert_eq!(QuotedSku::LAST, QuotedSku::Gizmo03);
+}
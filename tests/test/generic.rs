@@ -0,0 +1,66 @@
+use crate::test::types::{Seventeen, Slot, Three, Wrapped};
+use enumoid::EnumMap;
+use enumoid::EnumPackedMap;
+use enumoid::EnumSet;
+use enumoid::EnumSize;
+use enumoid::Enumoid;
+
+#[test]
+fn test_generic_size() {
+  assert_eq!(Slot::<Three>::SIZE, 5);
+  assert_eq!(Slot::<Slot<Three>>::SIZE, 7);
+  assert_eq!(Slot::<Seventeen>::SIZE, 19);
+  assert_eq!(Wrapped::<Slot<Three>>::SIZE, 5);
+  assert_eq!(Slot::<Seventeen>::LAST.into_word(), 18);
+  assert_eq!(EnumSize::<Slot<Three>>::LAST.into_word(), 5u16);
+  assert_eq!(
+    Slot::<Slot<Three>>::from_word(5),
+    Some(Slot::Filled(Slot::Reserved))
+  );
+}
+
+#[test]
+fn test_generic_map() {
+  let mut map =
+    EnumMap::<Slot<Three>, u32>::new_with(|key| key.into_word().into());
+  map[Slot::Reserved] += 10;
+  assert_eq!(
+    map.iter().collect::<Vec<_>>(),
+    vec![
+      (Slot::Empty, &0),
+      (Slot::Filled(Three::A), &1),
+      (Slot::Filled(Three::B), &2),
+      (Slot::Filled(Three::C), &3),
+      (Slot::Reserved, &14),
+    ]
+  );
+  assert_eq!(map.as_slice().len(), 5);
+}
+
+#[test]
+fn test_generic_set() {
+  let mut set = EnumSet::<Slot<Seventeen>>::new();
+  set.insert(Slot::Empty);
+  set.insert(Slot::Filled(Seventeen::Q));
+  set.insert(Slot::Reserved);
+  assert_eq!(
+    set.iter().collect::<Vec<_>>(),
+    vec![Slot::Empty, Slot::Filled(Seventeen::Q), Slot::Reserved]
+  );
+  assert_eq!(EnumSet::<Slot<Seventeen>>::new_all().count(), 19);
+}
+
+#[test]
+fn test_generic_packed_map() {
+  let mut map = EnumPackedMap::<Three, Slot<Three>>::new_with(Slot::Filled);
+  map.set(Three::B, Slot::Reserved);
+  assert_eq!(map.clone(), map);
+  assert_eq!(
+    map.iter().collect::<Vec<_>>(),
+    vec![
+      (Three::A, Slot::Filled(Three::A)),
+      (Three::B, Slot::Reserved),
+      (Three::C, Slot::Filled(Three::C)),
+    ]
+  );
+}
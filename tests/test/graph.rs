@@ -0,0 +1,139 @@
+use crate::test::types::{Seventeen, Three};
+use enumoid::EnumMap;
+use enumoid::EnumOptionMap;
+use enumoid::EnumRelation;
+use enumoid::EnumSet;
+
+type Graph = EnumMap<Seventeen, EnumSet<Seventeen>>;
+
+fn graph(edges: &[(Seventeen, Seventeen)]) -> Graph {
+  let relation: EnumRelation<Seventeen, Seventeen> =
+    edges.iter().copied().collect();
+  relation.into()
+}
+
+#[test]
+fn test_reachable_from() {
+  use Seventeen::*;
+  let g = graph(&[(A, B), (B, C), (C, A), (D, A)]);
+
+  assert_eq!(g.reachable_from(A), [A, B, C].into_iter().collect());
+  assert_eq!(g.reachable_from(D), [A, B, C, D].into_iter().collect());
+  assert_eq!(
+    g.reachable_from(Q),
+    [Q].into_iter().collect(),
+    "Expected a node to reach itself"
+  );
+}
+
+#[test]
+fn test_traversal_orders() {
+  use Seventeen::*;
+  let g = graph(&[(A, C), (A, B), (B, D), (C, E), (D, E)]);
+
+  assert_eq!(g.bfs_order(A).as_slice(), &[A, B, C, D, E]);
+  assert_eq!(g.dfs_order(A).as_slice(), &[A, B, D, E, C]);
+  assert_eq!(g.dfs_order(E).as_slice(), &[E]);
+}
+
+#[test]
+fn test_shortest_path() {
+  use Seventeen::*;
+  let g = graph(&[(A, B), (B, C), (C, D), (A, C)]);
+
+  assert_eq!(g.shortest_path(A, D).unwrap().as_slice(), &[A, C, D]);
+  assert_eq!(g.shortest_path(B, B).unwrap().as_slice(), &[B]);
+  assert!(g.shortest_path(D, A).is_none(), "Expected no path");
+}
+
+#[test]
+fn test_topological_sort() {
+  use Seventeen::*;
+  let g = graph(&[(C, A), (A, B), (D, B), (Q, C)]);
+
+  let order = g.topological_sort().expect("Expected an acyclic graph");
+  assert!(order.is_full(), "Expected every node in the order");
+  let position = |key| order.as_slice().iter().position(|&x| x == key);
+  for &(a, b) in &[(C, A), (A, B), (D, B), (Q, C)] {
+    assert!(position(a) < position(b), "Expected {a:?} before {b:?}");
+  }
+}
+
+#[test]
+fn test_topological_sort_cycle() {
+  use Seventeen::*;
+  let g = graph(&[(A, B), (B, E), (E, F), (F, B), (F, G)]);
+
+  let cycle = g.topological_sort().expect_err("Expected a cycle");
+  let cycle = cycle.as_slice();
+  assert_eq!(cycle.len(), 3);
+  for (i, &node) in cycle.iter().enumerate() {
+    let next = cycle[(i + 1) % cycle.len()];
+    assert!(g[node].contains(next), "Expected {node:?} -> {next:?}");
+  }
+
+  let g = graph(&[(A, B), (C, C)]);
+  assert_eq!(
+    g.topological_sort().unwrap_err().as_slice(),
+    &[C],
+    "Expected a self-loop as the witness"
+  );
+}
+
+#[test]
+fn test_strongly_connected_components() {
+  use Seventeen::*;
+  let g = graph(&[(B, C), (C, D), (D, B), (D, E), (E, F), (F, E), (Q, A)]);
+
+  let components = g.strongly_connected_components();
+  assert_eq!(components[A], A);
+  assert_eq!(components[B], B);
+  assert_eq!(components[C], B);
+  assert_eq!(components[D], B);
+  assert_eq!(components[E], E);
+  assert_eq!(components[F], E);
+  assert_eq!(components[Q], Q);
+}
+
+#[test]
+fn test_relation_as_graph() {
+  let relation: EnumRelation<Three, Three> =
+    [(Three::B, Three::A), (Three::C, Three::B)]
+      .into_iter()
+      .collect();
+
+  assert_eq!(
+    relation.as_map().topological_sort().unwrap().as_slice(),
+    &[Three::C, Three::B, Three::A]
+  );
+}
+
+#[test]
+fn test_shortest_paths() {
+  use Seventeen::*;
+  let mut weights = EnumMap::<Seventeen, EnumOptionMap<Seventeen, u32>>::new();
+  weights[A].insert(B, 7);
+  weights[A].insert(C, 2);
+  weights[C].insert(B, 3);
+  weights[B].insert(D, 1);
+  weights[D].insert(A, 1);
+
+  let paths = weights.shortest_paths(A);
+  assert_eq!(paths.distance(A), Some(0));
+  assert_eq!(paths.distance(B), Some(5));
+  assert_eq!(paths.distance(D), Some(6));
+  assert_eq!(paths.distance(Q), None, "Expected Q to be unreachable");
+  assert_eq!(paths.predecessor(B), Some(C));
+  assert_eq!(paths.predecessor(A), None);
+  assert_eq!(paths.path_to(D).unwrap().as_slice(), &[A, C, B, D]);
+  assert!(paths.path_to(Q).is_none());
+  assert_eq!(paths.distances().count(), 4);
+}
+
+#[test]
+#[should_panic(expected = "Shortest path edge weight is negative")]
+fn test_shortest_paths_negative() {
+  let mut weights = EnumMap::<Three, EnumOptionMap<Three, i32>>::new();
+  weights[Three::A].insert(Three::B, -1);
+  weights.shortest_paths(Three::A);
+}
@@ -1,5 +1,7 @@
 use crate::test::drop_tracker::DropTracker;
-use crate::test::types::Three;
+use crate::test::types::{Three, ThreeHundred};
+use enumoid::CheckedArithTrait;
+use enumoid::EnumIndex;
 use enumoid::EnumMap;
 use enumoid::EnumOptionMap;
 use std::cell::Cell;
@@ -654,3 +656,146 @@ fn test_iter_mut_double_ended() {
   assert_eq!(map[Three::A], 11, "Expected front mutation to persist");
   assert_eq!(map[Three::C], 31, "Expected back mutation to persist");
 }
+
+fn numeric_map(a: i64, b: i64, c: i64) -> EnumMap<Three, i64> {
+  [(Three::A, a), (Three::B, b), (Three::C, c)]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn test_elementwise_arithmetic() {
+  let x = numeric_map(1, 2, 3);
+  let y = numeric_map(10, 20, 30);
+
+  assert_eq!(x + y, numeric_map(11, 22, 33), "Expected element-wise sum");
+  assert_eq!(
+    y - x,
+    numeric_map(9, 18, 27),
+    "Expected element-wise difference"
+  );
+  assert_eq!(
+    -x,
+    numeric_map(-1, -2, -3),
+    "Expected element-wise negation"
+  );
+  assert_eq!(
+    x * 4,
+    numeric_map(4, 8, 12),
+    "Expected scalar multiplication"
+  );
+  assert_eq!(y / 10, numeric_map(1, 2, 3), "Expected scalar division");
+
+  let mut z = x;
+  z += y;
+  z -= numeric_map(1, 1, 1);
+  z *= 2;
+  z /= 4;
+  assert_eq!(z, numeric_map(5, 10, 16));
+}
+
+#[test]
+fn test_elementwise_sum_and_dot() {
+  let maps = [numeric_map(1, 2, 3), numeric_map(4, 5, 6)];
+  let total: EnumMap<Three, i64> = maps.into_iter().sum();
+  assert_eq!(total, numeric_map(5, 7, 9), "Expected sum over iterator");
+
+  assert_eq!(numeric_map(1, 2, 3).dot(&numeric_map(4, -5, 6)), 12);
+}
+
+#[test]
+fn test_elementwise_min_max() {
+  let x = numeric_map(1, 5, 3);
+  let y = numeric_map(4, 2, 3);
+
+  assert_eq!(x.min_elementwise(&y), numeric_map(1, 2, 3));
+  assert_eq!(x.max_elementwise(&y), numeric_map(4, 5, 3));
+}
+
+#[test]
+fn test_checked_and_saturating() {
+  let x = numeric_map(1, i64::MAX, -3);
+  let y = numeric_map(1, 1, 1);
+
+  assert_eq!(x.checked_add(&y), None, "Expected overflow to be detected");
+  assert_eq!(x.saturating_add(&y), numeric_map(2, i64::MAX, -2));
+  assert_eq!(x.checked_sub(&y), Some(numeric_map(0, i64::MAX - 1, -4)));
+
+  let small = numeric_map(0, 1, 2);
+  let min = numeric_map(i64::MIN, 0, 0);
+  assert_eq!(min.saturating_sub(&small), numeric_map(i64::MIN, -1, -2));
+  assert_eq!(min.checked_sub(&numeric_map(1, 0, 0)), None);
+}
+
+fn checked_total<V: CheckedArithTrait>(
+  maps: &[EnumMap<Three, V>],
+) -> Option<EnumMap<Three, V>> {
+  let (first, rest) = maps.split_first()?;
+  rest
+    .iter()
+    .try_fold(*first, |total, map| total.checked_add(map))
+}
+
+#[test]
+fn test_generic_checked_arith() {
+  let maps = [numeric_map(1, 2, 3), numeric_map(4, 5, 6)];
+  assert_eq!(checked_total(&maps), Some(numeric_map(5, 7, 9)));
+  assert_eq!(
+    checked_total(&[numeric_map(i64::MAX, 0, 0), numeric_map(1, 0, 0)]),
+    None
+  );
+}
+
+#[test]
+fn test_argmin_argmax() {
+  let map = numeric_map(3, 7, 7);
+
+  assert_eq!(map.argmax(), Three::B, "Expected ties to pick lowest index");
+  assert_eq!(map.argmin(), Three::A);
+  assert_eq!(map.min_max(), (Three::A, Three::B));
+  assert_eq!(numeric_map(2, 2, 2).min_max(), (Three::A, Three::A));
+}
+
+#[test]
+fn test_argmin_argmax_by_key() {
+  let map = numeric_map(-5, 4, 5);
+
+  assert_eq!(map.argmax_by_key(|v| v.abs()), Three::A);
+  assert_eq!(map.argmin_by_key(|v| v.abs()), Three::B);
+  assert_eq!(map.argmax_by_key(|_| 0), Three::A);
+}
+
+#[test]
+fn test_keys_sorted_by() {
+  let map = numeric_map(2, 1, 2);
+
+  assert_eq!(
+    map.keys_sorted_by(|a, b| a.cmp(b)).as_slice(),
+    &[Three::B, Three::A, Three::C],
+    "Expected ascending values with ties in index order"
+  );
+  assert_eq!(
+    map.keys_sorted_by(|a, b| b.cmp(a)).as_slice(),
+    &[Three::A, Three::C, Three::B],
+    "Expected descending values with ties in index order"
+  );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_new_boxed() {
+  let map = EnumMap::<ThreeHundred, [u64; 32]>::new_boxed();
+  assert!(
+    map
+      .as_slice()
+      .iter()
+      .all(|cell| cell.iter().all(|&x| x == 0))
+  );
+
+  let map = EnumMap::<ThreeHundred, [u64; 32]>::new_with_boxed(|key| {
+    [EnumIndex::from_value(key).into_usize() as u64; 32]
+  });
+  assert_eq!(map[ThreeHundred::A1], [0; 32]);
+  assert_eq!(map[ThreeHundred::A300], [299; 32]);
+  assert_eq!(*map, EnumMap::new_with(|key| *map.get(key)));
+}
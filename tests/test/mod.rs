@@ -1,10 +1,26 @@
+pub mod bag;
+pub mod bimap;
+pub mod deque;
+pub mod discriminant;
+pub mod disjoint_sets;
 pub mod drop_tracker;
+pub mod generate;
+pub mod generic;
+pub mod graph;
 pub mod map;
+pub mod nested;
+#[cfg(feature = "std")]
+pub mod once_map;
 pub mod opt_map;
 pub mod order;
+pub mod packed_map;
+pub mod permutation;
+pub mod priority_queue;
+pub mod relation;
 #[cfg(feature = "serde")]
 pub mod serde;
 pub mod set;
 pub mod sizes;
+pub mod skip;
 pub mod types;
 pub mod vec;
@@ -0,0 +1,105 @@
+use crate::test::types::{Three, TwoFiftyFive};
+use enumoid::EnumIndex;
+use enumoid::EnumMap;
+use enumoid::EnumPackedMap;
+use enumoid::EnumSet;
+use enumoid::EnumSize;
+use enumoid::Enumoid;
+use std::mem;
+
+#[test]
+fn test_enum_index() {
+  assert_eq!(EnumIndex::<Three>::SIZE, 3);
+  assert_eq!(EnumIndex::<Three>::LAST, EnumIndex::from_value(Three::C));
+  assert_eq!(
+    <EnumIndex<Three> as Enumoid>::iter()
+      .map(EnumIndex::into_value)
+      .collect::<Vec<_>>(),
+    vec![Three::A, Three::B, Three::C]
+  );
+  let mut set = EnumSet::<EnumIndex<Three>>::new();
+  set.insert(EnumIndex::from_value(Three::B));
+  assert_eq!(
+    set.iter().map(EnumIndex::into_value).collect::<Vec<_>>(),
+    [Three::B]
+  );
+}
+
+#[test]
+fn test_enum_size() {
+  assert_eq!(EnumSize::<Three>::SIZE, 4);
+  assert_eq!(EnumSize::<Three>::FIRST, EnumSize::EMPTY);
+  assert_eq!(EnumSize::<Three>::LAST, EnumSize::FULL);
+  assert_eq!(
+    <EnumSize<Three> as Enumoid>::iter()
+      .map(EnumSize::into_usize)
+      .collect::<Vec<_>>(),
+    vec![0, 1, 2, 3]
+  );
+  let mut costs = EnumMap::<EnumSize<Three>, u32>::new();
+  costs[EnumSize::FULL] = 30;
+  assert_eq!(costs.as_slice(), [0, 0, 0, 30]);
+  let packed =
+    EnumPackedMap::<Three, EnumSize<Three>>::new_with(EnumSize::from_last);
+  assert_eq!(packed.get(Three::C), EnumSize::FULL);
+}
+
+#[test]
+fn test_enum_size_word_widening() {
+  assert_eq!(TwoFiftyFive::SIZE, u8::MAX as usize);
+  assert_eq!(EnumSize::<TwoFiftyFive>::SIZE, 256);
+  let full: u16 = Enumoid::into_word(EnumSize::<TwoFiftyFive>::FULL);
+  assert_eq!(full, 255);
+  assert_eq!(
+    <EnumSize<TwoFiftyFive> as Enumoid>::from_word(255),
+    Some(EnumSize::FULL)
+  );
+  assert_eq!(<EnumSize<TwoFiftyFive> as Enumoid>::from_word(256), None);
+  let set = EnumSet::<EnumSize<TwoFiftyFive>>::new_all();
+  assert_eq!(set.count(), 256);
+  assert_eq!(
+    <EnumSize<TwoFiftyFive> as Enumoid>::iter().next_back(),
+    Some(EnumSize::FULL)
+  );
+}
+
+#[test]
+fn test_enum_size_word_narrow() {
+  assert_eq!(mem::size_of::<EnumIndex<EnumSize<Three>>>(), 1);
+  assert_eq!(mem::size_of::<EnumIndex<EnumSize<TwoFiftyFive>>>(), 2);
+  let full: u8 = Enumoid::into_word(EnumSize::<Three>::FULL);
+  assert_eq!(full, 3);
+}
+
+#[test]
+fn test_enum_size_methods() {
+  let size = EnumSize::<Three>::from_usize(2).unwrap();
+
+  // Methods on the values of `Three` within the size.
+  assert_eq!(size.into_index_word(), 2u8);
+  assert_eq!(size.next_value(Three::A), Some(Three::B));
+  assert_eq!(size.values().collect::<Vec<_>>(), vec![Three::A, Three::B]);
+
+  // Method calls agree with the `Enumoid` methods on the sizes themselves.
+  assert_eq!(size.into_word(), Enumoid::into_word(size));
+  assert_eq!(size.next(), EnumSize::from_usize(3));
+  assert_eq!(size.next(), Enumoid::next(size));
+  assert_eq!(EnumSize::<Three>::EMPTY.prev(), None);
+  assert_eq!(
+    EnumSize::<Three>::iter()
+      .map(EnumSize::into_usize)
+      .collect::<Vec<_>>(),
+    vec![0, 1, 2, 3]
+  );
+}
+
+#[test]
+fn test_sizes_of_sizes() {
+  assert_eq!(EnumSize::<EnumSize<Three>>::SIZE, 5);
+  assert_eq!(
+    <EnumSize<EnumSize<Three>> as Enumoid>::LAST.into_last(),
+    Some(EnumSize::FULL)
+  );
+  let full: usize = Enumoid::into_word(EnumSize::<EnumSize<Three>>::FULL);
+  assert_eq!(full, 4);
+}
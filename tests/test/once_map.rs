@@ -0,0 +1,124 @@
+use crate::test::drop_tracker::DropTracker;
+use crate::test::types::Three;
+use enumoid::EnumOnceMap;
+use std::cell::Cell;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
+
+static STATIC_MAP: EnumOnceMap<Three, String> = EnumOnceMap::new();
+
+#[test]
+fn test_empty_state() {
+  let map = EnumOnceMap::<Three, u16>::new();
+
+  assert_eq!(map.get(Three::A), None, "Expected new slot to be empty");
+  assert!(!map.contains(Three::B), "Expected new slot to be empty");
+  assert_eq!(
+    map.iter_initialized().count(),
+    0,
+    "Expected no initialised slots"
+  );
+}
+
+#[test]
+fn test_get_or_init() {
+  let map = EnumOnceMap::<Three, u16>::new();
+  let calls = Cell::new(0);
+
+  let value = map.get_or_init(Three::B, || {
+    calls.set(calls.get() + 1);
+    20
+  });
+  assert_eq!(*value, 20, "Expected initialised value");
+  let value = map.get_or_init(Three::B, || {
+    calls.set(calls.get() + 1);
+    30
+  });
+  assert_eq!(*value, 20, "Expected value from first initialisation");
+  assert_eq!(calls.get(), 1, "Expected initialiser to run once");
+  assert_eq!(map.get(Three::B), Some(&20));
+  assert_eq!(map.get(Three::A), None, "Expected other slots to be empty");
+}
+
+#[test]
+fn test_set() {
+  let mut map = EnumOnceMap::<Three, u16>::new();
+
+  assert_eq!(map.set(Three::C, 3), Ok(()));
+  assert_eq!(map.set(Three::C, 4), Err(4), "Expected slot to be occupied");
+  *map.get_mut(Three::C).unwrap() += 10;
+  assert_eq!(map.get(Three::C), Some(&13));
+  assert_eq!(map.get_mut(Three::A), None);
+}
+
+#[test]
+fn test_iter_initialized() {
+  let map = EnumOnceMap::<Three, u16>::new();
+  map.get_or_init(Three::C, || 30);
+  map.get_or_init(Three::A, || 10);
+
+  let collected: Vec<_> = map.iter_initialized().collect();
+  assert_eq!(
+    collected,
+    vec![(Three::A, &10), (Three::C, &30)],
+    "Expected iteration over initialised slots in key order"
+  );
+  assert_eq!(format!("{map:?}"), "{A: 10, C: 30}");
+}
+
+#[test]
+fn test_into_option_map() {
+  let map = EnumOnceMap::<Three, u16>::new();
+  map.get_or_init(Three::B, || 20);
+
+  let opt_map = map.into_option_map();
+  assert_eq!(opt_map.count(), 1);
+  assert_eq!(opt_map.get(Three::B), Some(&20));
+}
+
+#[test]
+fn test_static() {
+  let a = STATIC_MAP.get_or_init(Three::A, || "alpha".to_string());
+  assert_eq!(a, "alpha");
+  assert_eq!(STATIC_MAP.get(Three::A).map(String::as_str), Some("alpha"));
+}
+
+#[test]
+fn test_concurrent_init() {
+  let map = EnumOnceMap::<Three, u32>::new();
+  let calls = AtomicU32::new(0);
+
+  std::thread::scope(|s| {
+    for _ in 0..8 {
+      s.spawn(|| {
+        let value = map.get_or_init(Three::A, || {
+          calls.fetch_add(1, Ordering::SeqCst);
+          42
+        });
+        assert_eq!(*value, 42);
+      });
+    }
+  });
+  assert_eq!(calls.load(Ordering::SeqCst), 1, "Expected one initialiser");
+}
+
+#[test]
+fn test_drop() {
+  let drops = Cell::new(0);
+  {
+    let map = EnumOnceMap::<Three, DropTracker>::new();
+    map.get_or_init(Three::A, || DropTracker::new(1, &drops));
+    map.get_or_init(Three::C, || DropTracker::new(3, &drops));
+  }
+  assert_eq!(drops.get(), 2, "Expected initialised values to be dropped");
+
+  let drops = Cell::new(0);
+  {
+    let map = EnumOnceMap::<Three, DropTracker>::new();
+    map.get_or_init(Three::B, || DropTracker::new(2, &drops));
+    let opt_map = map.into_option_map();
+    assert_eq!(drops.get(), 0, "Expected values to move into the map");
+    assert_eq!(opt_map.get(Three::B).map(DropTracker::id), Some(2));
+  }
+  assert_eq!(drops.get(), 1, "Expected moved value to be dropped once");
+}
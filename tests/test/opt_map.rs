@@ -1,5 +1,5 @@
 use crate::test::drop_tracker::DropTracker;
-use crate::test::types::Three;
+use crate::test::types::{Three, ThreeHundred};
 use enumoid::EnumOptionMap;
 use enumoid::EnumSize;
 use std::cell::Cell;
@@ -868,3 +868,52 @@ fn test_into_iterator_owned_drops_remainder_when_abandoned() {
     "Expected the remaining populated value to drop when abandoned"
   );
 }
+
+#[test]
+fn test_argmin_argmax() {
+  let mut map = EnumOptionMap::<Three, i32>::new();
+  assert_eq!(map.argmax(), None, "Expected None for empty map");
+  assert_eq!(
+    map.argmin_by_key(|v| *v),
+    None,
+    "Expected None for empty map"
+  );
+  assert_eq!(map.min_max(), None, "Expected None for empty map");
+
+  map.insert(Three::B, 5);
+  map.insert(Three::C, 5);
+  assert_eq!(
+    map.argmax(),
+    Some(Three::B),
+    "Expected lowest index on ties"
+  );
+  assert_eq!(map.argmin(), Some(Three::B), "Expected absent keys ignored");
+
+  map.insert(Three::A, -9);
+  assert_eq!(map.min_max(), Some((Three::A, Three::B)));
+  assert_eq!(map.argmax_by_key(|v| v.abs()), Some(Three::A));
+  assert_eq!(map.argmin_by_key(|v| v.abs()), Some(Three::B));
+}
+
+#[test]
+fn test_keys_sorted_by() {
+  let mut map = EnumOptionMap::<Three, i32>::new();
+  map.insert(Three::C, 1);
+  map.insert(Three::A, 3);
+
+  assert_eq!(
+    map.keys_sorted_by(|a, b| a.cmp(b)).as_slice(),
+    &[Three::C, Three::A],
+    "Expected only present keys in value order"
+  );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_new_boxed() {
+  let mut map = EnumOptionMap::<ThreeHundred, [u64; 32]>::new_boxed();
+  assert!(map.is_empty());
+  assert_eq!(map.insert(ThreeHundred::A150, [7; 32]), None);
+  assert_eq!(map.get(ThreeHundred::A150), Some(&[7; 32]));
+  assert_eq!(map.get(ThreeHundred::A151), None);
+}
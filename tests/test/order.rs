@@ -1,6 +1,8 @@
 use crate::test::types::{
-  CompoundOnWideSeven, CompoundSeven, CompoundWideOnSeven, GoldenValues,
-  Seventeen, Sixteen, StructOne, StructThree, Three, ThreeHundred, WideThree,
+  AutoThree, Channel, CompoundOnWideSeven, CompoundSeven, CompoundWideOnSeven,
+  GoldenValues, OrderedCompound, RenamedCrate, ReprOrdered, ReprSparse,
+  ReprThree, ReversedStruct, Sensor, Seventeen, Sixteen, Slot, StructOne,
+  StructThree, Three, ThreeHundred, WideThree, WithSkipped, Wrapped,
 };
 use enumoid::{EnumIndex, EnumSize, Enumoid};
 
@@ -224,7 +226,7 @@ fn check_size_iterators<T: GoldenValues>() {
   let sizes = all_sizes::<T>();
   for (s, &size) in sizes.iter().enumerate() {
     assert_eq!(
-      size.iter().collect::<Vec<_>>(),
+      size.values().collect::<Vec<_>>(),
       values[..s].to_vec(),
       "EnumSize({s})::iter()"
     );
@@ -235,7 +237,7 @@ fn check_size_iterators<T: GoldenValues>() {
         Vec::new()
       };
       assert_eq!(
-        size.iter_from(from).collect::<Vec<_>>(),
+        size.values_from(from).collect::<Vec<_>>(),
         expected,
         "EnumSize({s})::iter_from({from:?})"
       );
@@ -243,7 +245,7 @@ fn check_size_iterators<T: GoldenValues>() {
     for (j, &until) in values.iter().enumerate() {
       let lim = (j + 1).min(s);
       assert_eq!(
-        size.iter_until(until).collect::<Vec<_>>(),
+        size.values_until(until).collect::<Vec<_>>(),
         values[..lim].to_vec(),
         "EnumSize({s})::iter_until({until:?})"
       );
@@ -257,7 +259,7 @@ fn check_size_iterators<T: GoldenValues>() {
           Vec::new()
         };
         assert_eq!(
-          size.iter_from_until(from, until).collect::<Vec<_>>(),
+          size.values_from_until(from, until).collect::<Vec<_>>(),
           expected,
           "EnumSize({s})::iter_from_until({from:?}, {until:?})"
         );
@@ -323,6 +325,20 @@ order_tests!(compound_on_wide_seven, CompoundOnWideSeven);
 order_tests!(compound_wide_on_seven, CompoundWideOnSeven);
 order_tests!(sixteen, Sixteen);
 order_tests!(seventeen, Seventeen);
+order_tests!(renamed_crate, RenamedCrate);
+order_tests!(auto_three, AutoThree);
+order_tests!(repr_three, ReprThree);
+order_tests!(repr_sparse, ReprSparse);
+order_tests!(ordered_compound, OrderedCompound);
+order_tests!(reversed_struct, ReversedStruct);
+order_tests!(repr_ordered, ReprOrdered);
+order_tests!(with_skipped, WithSkipped);
+order_tests!(generic_enum, Slot<Three>);
+order_tests!(generic_struct, Wrapped<Slot<Three>>);
+order_tests!(generated_channel, Channel);
+order_tests!(sensor_from_file, Sensor);
+order_tests!(enum_size, EnumSize<StructOne>);
+order_tests!(enum_index, EnumIndex<StructOne>);
 
 // `ThreeHundred` is deliberately excluded from `order_tests!`: with 300
 // variants the combinatorial iterator checks (up to O(n^4)) would be
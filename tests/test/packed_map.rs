@@ -0,0 +1,89 @@
+use crate::test::types::{Seventeen, Sixteen, StructOne, Three, ThreeHundred};
+use enumoid::EnumMap;
+use enumoid::EnumPackedMap;
+use enumoid::Enumoid;
+use std::mem;
+
+fn colour(key: ThreeHundred) -> Three {
+  match key.into_word() % 3 {
+    0 => Three::A,
+    1 => Three::B,
+    _ => Three::C,
+  }
+}
+
+#[test]
+fn test_packed_size() {
+  assert_eq!(
+    mem::size_of::<EnumPackedMap<ThreeHundred, Three>>(),
+    2 * 300usize.div_ceil(8),
+    "Expected two bit planes for three values"
+  );
+  assert_eq!(
+    mem::size_of::<EnumPackedMap<ThreeHundred, Sixteen, usize>>(),
+    4 * 300usize.div_ceil(usize::BITS as usize) * mem::size_of::<usize>(),
+    "Expected four bit planes for sixteen values"
+  );
+  assert_eq!(
+    mem::size_of::<EnumPackedMap<ThreeHundred, StructOne>>(),
+    0,
+    "Expected no planes for a single value"
+  );
+}
+
+#[test]
+fn test_get_and_set() {
+  let mut map = EnumPackedMap::<Three, Seventeen>::new_with(|_| Seventeen::Q);
+
+  assert_eq!(map.get(Three::B), Seventeen::Q);
+  assert_eq!(map.set(Three::B, Seventeen::E), Seventeen::Q);
+  assert_eq!(map.set(Three::B, Seventeen::A), Seventeen::E);
+  assert_eq!(map.get(Three::A), Seventeen::Q);
+  assert_eq!(map.get(Three::B), Seventeen::A);
+  assert_eq!(
+    map.iter().collect::<Vec<_>>(),
+    vec![
+      (Three::A, Seventeen::Q),
+      (Three::B, Seventeen::A),
+      (Three::C, Seventeen::Q)
+    ]
+  );
+  assert_eq!(format!("{map:?}"), "{A: Q, B: A, C: Q}");
+}
+
+#[test]
+fn test_count_of() {
+  let map = EnumPackedMap::<ThreeHundred, Three, usize>::new_with(colour);
+
+  assert_eq!(map.count_of(Three::A), 100);
+  assert_eq!(map.count_of(Three::B), 100);
+  assert_eq!(map.count_of(Three::C), 100);
+  assert!(map.keys_of(Three::B).contains(ThreeHundred::A2));
+  assert!(!map.keys_of(Three::B).contains(ThreeHundred::A3));
+
+  let single = EnumPackedMap::<Seventeen, StructOne>::new_with(|_| StructOne);
+  assert_eq!(single.count_of(StructOne), 17);
+}
+
+#[test]
+fn test_map_conversion() {
+  let map = EnumMap::<ThreeHundred, Three>::new_with(colour);
+  let packed = EnumPackedMap::<ThreeHundred, Three>::from(map);
+
+  assert_eq!(packed.to_map(), map);
+  assert_eq!(EnumMap::from(packed), map, "Expected a round trip");
+  assert!(
+    ThreeHundred::iter().all(|key| packed.get(key) == colour(key)),
+    "Expected every value to be preserved"
+  );
+}
+
+#[test]
+fn test_copy_and_eq() {
+  let a = EnumPackedMap::<Three, Three>::new_with(|key| key);
+  let mut b = a;
+  assert_eq!(a, b);
+  b.set(Three::C, Three::A);
+  assert_ne!(a, b);
+  assert_eq!(a.clone(), a);
+}
@@ -0,0 +1,126 @@
+use crate::test::types::{CompoundSeven, StructOne, Three};
+use enumoid::EnumPermutation;
+use enumoid::EnumVec;
+use enumoid::Parity;
+use std::collections::HashSet;
+
+fn perm(order: [Three; 3]) -> EnumPermutation<Three> {
+  EnumPermutation::from_order(order.into_iter().collect()).unwrap()
+}
+
+#[test]
+fn test_identity() {
+  let id = EnumPermutation::<Three>::identity();
+
+  assert!(id.is_identity());
+  assert_eq!(id.apply(Three::B), Three::B);
+  assert_eq!(id.parity(), Parity::Even);
+  assert_eq!(id, EnumPermutation::default());
+}
+
+#[test]
+fn test_from_order() {
+  let p = perm([Three::B, Three::C, Three::A]);
+
+  assert_eq!(p.apply(Three::A), Three::B);
+  assert_eq!(p.apply(Three::C), Three::A);
+  assert_eq!(p.apply_inverse(Three::B), Three::A);
+  assert_eq!(p.order().as_slice(), &[Three::B, Three::C, Three::A]);
+  assert_eq!(format!("{p:?}"), "{A: B, B: C, C: A}");
+}
+
+#[test]
+fn test_from_order_invalid() {
+  let short: EnumVec<Three, Three> = [Three::A, Three::B].into_iter().collect();
+  assert!(
+    EnumPermutation::from_order(short).is_none(),
+    "Expected partial order to be rejected"
+  );
+  let duplicate: EnumVec<Three, Three> =
+    [Three::A, Three::B, Three::A].into_iter().collect();
+  assert!(
+    EnumPermutation::from_order(duplicate).is_none(),
+    "Expected duplicate value to be rejected"
+  );
+}
+
+#[test]
+fn test_inverse_and_compose() {
+  let p = perm([Three::B, Three::C, Three::A]);
+  let q = perm([Three::A, Three::C, Three::B]);
+
+  assert!(p.compose(&p.inverse()).is_identity());
+  assert!(p.inverse().compose(&p).is_identity());
+  let pq = p.compose(&q);
+  for x in [Three::A, Three::B, Three::C] {
+    assert_eq!(pq.apply(x), p.apply(q.apply(x)), "Expected q then p");
+    assert_eq!(pq.apply_inverse(pq.apply(x)), x);
+  }
+}
+
+#[test]
+fn test_swap() {
+  let mut p = EnumPermutation::<Three>::identity();
+  p.swap(Three::A, Three::C);
+
+  assert_eq!(p.order().as_slice(), &[Three::C, Three::B, Three::A]);
+  assert_eq!(p.apply_inverse(Three::C), Three::A);
+  assert_eq!(p.parity(), Parity::Odd);
+}
+
+#[test]
+fn test_cycles() {
+  let p = perm([Three::B, Three::A, Three::C]);
+  let cycles: Vec<Vec<Three>> =
+    p.cycles().map(|c| c.as_slice().to_vec()).collect();
+
+  assert_eq!(cycles, vec![vec![Three::A, Three::B], vec![Three::C]]);
+  assert_eq!(p.cycle_count(), 2);
+
+  let p = perm([Three::C, Three::A, Three::B]);
+  let cycles: Vec<Vec<Three>> =
+    p.cycles().map(|c| c.as_slice().to_vec()).collect();
+  assert_eq!(cycles, vec![vec![Three::A, Three::C, Three::B]]);
+  assert_eq!(p.parity(), Parity::Even);
+}
+
+#[test]
+fn test_iter_lexicographic() {
+  let orders: Vec<Vec<Three>> = EnumPermutation::<Three>::iter_lexicographic()
+    .map(|p| p.order().as_slice().to_vec())
+    .collect();
+  use Three::*;
+  assert_eq!(
+    orders,
+    vec![
+      vec![A, B, C],
+      vec![A, C, B],
+      vec![B, A, C],
+      vec![B, C, A],
+      vec![C, A, B],
+      vec![C, B, A],
+    ]
+  );
+}
+
+#[test]
+fn test_iter_heap() {
+  let perms: Vec<_> = EnumPermutation::<CompoundSeven>::iter_heap().collect();
+  assert_eq!(perms.len(), 5040, "Expected 7! permutations");
+  assert!(perms[0].is_identity(), "Expected identity first");
+  let distinct: HashSet<_> = perms.iter().collect();
+  assert_eq!(distinct.len(), 5040, "Expected no repeated permutations");
+  for pair in perms.windows(2) {
+    let step = pair[0].inverse().compose(&pair[1]);
+    assert_eq!(step.parity(), Parity::Odd, "Expected a single swap");
+  }
+}
+
+#[test]
+fn test_iter_single() {
+  assert_eq!(EnumPermutation::<StructOne>::iter_heap().count(), 1);
+  assert_eq!(
+    EnumPermutation::<StructOne>::iter_lexicographic().count(),
+    1
+  );
+}
@@ -0,0 +1,126 @@
+use crate::test::types::{Seventeen, Three};
+use enumoid::EnumPriorityQueue;
+use enumoid::Enumoid;
+
+#[test]
+fn test_empty_state() {
+  let mut queue = EnumPriorityQueue::<Three, u32>::new();
+
+  assert!(queue.is_empty(), "Expected new queue to be empty");
+  assert_eq!(queue.count(), 0);
+  assert!(!queue.contains(Three::A));
+  assert_eq!(queue.peek(), None);
+  assert_eq!(queue.pop_min(), None);
+  assert_eq!(queue.remove(Three::B), None);
+}
+
+#[test]
+fn test_push_and_pop() {
+  let mut queue = EnumPriorityQueue::<Three, u32>::new();
+
+  assert_eq!(queue.push_or_update(Three::A, 5), None);
+  assert_eq!(queue.push_or_update(Three::B, 2), None);
+  assert_eq!(queue.push_or_update(Three::C, 9), None);
+  assert_eq!(queue.count(), 3);
+  assert_eq!(queue.priority(Three::C), Some(&9));
+  assert_eq!(queue.peek(), Some((Three::B, &2)));
+  assert_eq!(format!("{queue:?}"), "{A: 5, B: 2, C: 9}");
+
+  assert_eq!(queue.pop_min(), Some((Three::B, 2)));
+  assert_eq!(queue.pop_min(), Some((Three::A, 5)));
+  assert_eq!(queue.pop_min(), Some((Three::C, 9)));
+  assert!(queue.is_empty());
+}
+
+#[test]
+fn test_update_priority() {
+  let mut queue = EnumPriorityQueue::<Three, u32>::new();
+  queue.push_or_update(Three::A, 5);
+  queue.push_or_update(Three::B, 2);
+  queue.push_or_update(Three::C, 9);
+
+  assert_eq!(
+    queue.push_or_update(Three::C, 1),
+    Some(9),
+    "Expected decrease"
+  );
+  assert_eq!(queue.peek(), Some((Three::C, &1)));
+  assert_eq!(
+    queue.push_or_update(Three::C, 7),
+    Some(1),
+    "Expected increase"
+  );
+  assert_eq!(queue.peek(), Some((Three::B, &2)));
+  assert_eq!(queue.count(), 3, "Expected updates to not add entries");
+}
+
+#[test]
+fn test_ties_in_index_order() {
+  let mut queue = EnumPriorityQueue::<Three, u32>::new();
+  queue.push_or_update(Three::C, 1);
+  queue.push_or_update(Three::A, 1);
+  queue.push_or_update(Three::B, 1);
+
+  assert_eq!(queue.pop_min(), Some((Three::A, 1)));
+  assert_eq!(queue.pop_min(), Some((Three::B, 1)));
+  assert_eq!(queue.pop_min(), Some((Three::C, 1)));
+}
+
+#[test]
+fn test_remove() {
+  let mut queue = EnumPriorityQueue::<Seventeen, i32>::new();
+  for (i, key) in Seventeen::iter().enumerate() {
+    queue.push_or_update(key, (i as i32 * 7) % 17);
+  }
+
+  assert_eq!(queue.remove(Seventeen::C), Some(14));
+  assert_eq!(queue.remove(Seventeen::C), None, "Expected already removed");
+  assert!(!queue.contains(Seventeen::C));
+  assert_eq!(
+    queue.remove(Seventeen::A),
+    Some(0),
+    "Expected minimum removed"
+  );
+  assert_eq!(queue.count(), 15);
+
+  let mut previous = i32::MIN;
+  while let Some((key, priority)) = queue.pop_min() {
+    assert!(priority >= previous, "Expected non-decreasing priorities");
+    assert!(!queue.contains(key));
+    previous = priority;
+  }
+}
+
+#[test]
+fn test_heap_order_matches_sort() {
+  let mut queue = EnumPriorityQueue::<Seventeen, u32>::new();
+  let mut expected = Vec::new();
+  for (i, key) in Seventeen::iter().enumerate() {
+    let priority = ((i * 11) % 17) as u32;
+    queue.push_or_update(key, priority + 100);
+    expected.push((priority, key));
+  }
+  for (i, key) in Seventeen::iter().enumerate() {
+    queue.push_or_update(key, ((i * 11) % 17) as u32);
+  }
+  expected.sort_by_key(|&(priority, _)| priority);
+
+  let popped: Vec<_> = std::iter::from_fn(|| queue.pop_min())
+    .map(|(key, priority)| (priority, key))
+    .collect();
+  assert_eq!(popped, expected);
+}
+
+#[test]
+fn test_clear() {
+  let mut queue = EnumPriorityQueue::<Three, u32>::new();
+  queue.push_or_update(Three::A, 1);
+  queue.push_or_update(Three::B, 2);
+  let copy = queue.clone();
+  queue.clear();
+
+  assert!(queue.is_empty());
+  assert!(!queue.contains(Three::A));
+  assert_eq!(copy.count(), 2, "Expected clone to be unaffected");
+  assert_eq!(copy.priority(Three::B), Some(&2));
+}
@@ -0,0 +1,166 @@
+use crate::test::types::{Seventeen, Three, WideThree};
+use enumoid::EnumRelation;
+use enumoid::EnumSet;
+use enumoid::Enumoid;
+
+fn chain() -> EnumRelation<Three, Three> {
+  [(Three::A, Three::B), (Three::B, Three::C)]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn test_empty_state() {
+  let rel = EnumRelation::<Three, WideThree>::new();
+
+  assert!(rel.is_empty(), "Expected new relation to be empty");
+  assert_eq!(rel.count(), 0);
+  assert!(!rel.related(Three::A, WideThree::A));
+  assert_eq!(rel.iter().count(), 0, "Expected no pairs");
+}
+
+#[test]
+fn test_relate_and_unrelate() {
+  let mut rel = EnumRelation::<Three, WideThree>::new();
+
+  assert!(!rel.relate(Three::A, WideThree::C), "Expected new pair");
+  assert!(rel.relate(Three::A, WideThree::C), "Expected existing pair");
+  rel.set(Three::C, WideThree::B, true);
+  assert!(rel.related(Three::A, WideThree::C));
+  assert!(!rel.related(Three::C, WideThree::A));
+  assert_eq!(rel.count(), 2);
+  assert_eq!(
+    rel.iter().collect::<Vec<_>>(),
+    vec![(Three::A, WideThree::C), (Three::C, WideThree::B)],
+    "Expected pairs in row order"
+  );
+  assert_eq!(format!("{rel:?}"), "{(A, C), (C, B)}");
+
+  assert!(
+    rel.unrelate(Three::A, WideThree::C),
+    "Expected removed pair"
+  );
+  assert!(!rel.unrelate(Three::A, WideThree::C), "Expected no pair");
+  assert_eq!(rel.count(), 1);
+  rel.clear();
+  assert!(rel.is_empty());
+}
+
+#[test]
+fn test_rows_and_columns() {
+  let mut rel = EnumRelation::<Three, Seventeen>::new();
+  rel.relate(Three::A, Seventeen::Q);
+  rel.relate(Three::C, Seventeen::Q);
+  rel.row_mut(Three::B).insert(Seventeen::A);
+
+  assert_eq!(rel.row(Three::A), &[Seventeen::Q].into_iter().collect());
+  assert_eq!(
+    rel.column(Seventeen::Q),
+    [Three::A, Three::C].into_iter().collect(),
+  );
+  assert_eq!(rel.column(Seventeen::B), EnumSet::new());
+}
+
+#[test]
+fn test_transpose() {
+  let rel: EnumRelation<Three, Seventeen> =
+    [(Three::A, Seventeen::Q), (Three::B, Seventeen::A)]
+      .into_iter()
+      .collect();
+  let transposed = rel.transpose();
+
+  assert!(transposed.related(Seventeen::Q, Three::A));
+  assert!(transposed.related(Seventeen::A, Three::B));
+  assert_eq!(transposed.count(), 2);
+  assert_eq!(
+    transposed.transpose(),
+    rel,
+    "Expected transpose to round trip"
+  );
+}
+
+#[test]
+fn test_compose() {
+  let first: EnumRelation<Three, Seventeen> =
+    [(Three::A, Seventeen::B), (Three::B, Seventeen::Q)]
+      .into_iter()
+      .collect();
+  let second: EnumRelation<Seventeen, WideThree> =
+    [(Seventeen::B, WideThree::C), (Seventeen::B, WideThree::A)]
+      .into_iter()
+      .collect();
+  let composed = first.compose(&second);
+
+  assert_eq!(
+    composed.iter().collect::<Vec<_>>(),
+    vec![(Three::A, WideThree::A), (Three::A, WideThree::C)]
+  );
+}
+
+#[test]
+fn test_union_and_intersection() {
+  let x = chain();
+  let y: EnumRelation<Three, Three> =
+    [(Three::A, Three::B), (Three::C, Three::A)]
+      .into_iter()
+      .collect();
+
+  assert_eq!(x.union(&y).count(), 3);
+  assert_eq!(x | y, x.union(&y));
+  assert_eq!(
+    x & y,
+    [(Three::A, Three::B)].into_iter().collect(),
+    "Expected only the shared pair"
+  );
+  assert!(x.intersection(&y).is_subset(&x));
+  assert!(!x.is_subset(&y));
+}
+
+#[test]
+fn test_properties() {
+  let identity = EnumRelation::<Three, Three>::identity();
+  assert!(identity.is_reflexive());
+  assert!(identity.is_symmetric());
+  assert!(identity.is_transitive());
+
+  let rel = chain();
+  assert!(!rel.is_reflexive());
+  assert!(!rel.is_symmetric());
+  assert!(!rel.is_transitive());
+  assert!((rel | rel.transpose()).is_symmetric());
+}
+
+#[test]
+fn test_closures() {
+  let rel = chain();
+
+  let transitive = rel.transitive_closure();
+  assert!(transitive.is_transitive());
+  assert!(transitive.related(Three::A, Three::C));
+  assert!(!transitive.related(Three::A, Three::A));
+  assert_eq!(transitive.count(), 3);
+
+  let reflexive = rel.reflexive_closure();
+  assert!(reflexive.is_reflexive());
+  assert_eq!(reflexive.count(), 5);
+
+  let both = rel.reflexive_transitive_closure();
+  assert!(both.is_reflexive() && both.is_transitive());
+  assert_eq!(both.count(), 6);
+}
+
+#[test]
+fn test_transitive_closure_cycle() {
+  let mut rel = EnumRelation::<Seventeen, Seventeen>::new();
+  for (a, b) in Seventeen::iter().zip(Seventeen::iter().skip(1)) {
+    rel.relate(a, b);
+  }
+  rel.relate(Seventeen::Q, Seventeen::A);
+
+  let closure = rel.transitive_closure();
+  assert_eq!(
+    closure,
+    EnumRelation::new_all(),
+    "Expected a cycle to relate every pair"
+  );
+}
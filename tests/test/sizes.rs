@@ -1,6 +1,7 @@
 use crate::test::types::{
-  CompoundOnWideSeven, CompoundSeven, CompoundWideOnSeven, Seventeen, Sixteen,
-  StructOne, StructThree, Three, ThreeHundred, WideThree,
+  AutoThree, AutoThreeHundred, CompoundOnWideSeven, CompoundSeven,
+  CompoundWideOnSeven, Seventeen, Sixteen, StructOne, StructThree, Three,
+  ThreeHundred, WideThree,
 };
 use enumoid::EnumArrayHelper;
 use enumoid::EnumMap;
@@ -74,3 +75,9 @@ fn test_seventeen() {
 fn test_three_hundred() {
   test_type::<ThreeHundred>(300, 2, 2, 38);
 }
+
+#[test]
+fn test_auto_index_type() {
+  test_type::<AutoThree>(3, 1, 1, 1);
+  test_type::<AutoThreeHundred>(303, 4, 2, 38);
+}
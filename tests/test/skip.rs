@@ -0,0 +1,95 @@
+use crate::test::types::{Three, WithSkipped};
+use enumoid::EnumIndex;
+use enumoid::EnumMap;
+use enumoid::EnumOptionMap;
+use enumoid::EnumPermutation;
+use enumoid::EnumSet;
+use enumoid::EnumVec;
+use enumoid::Enumoid;
+
+#[test]
+fn test_try_into_word() {
+  assert_eq!(WithSkipped::SIZE, 4);
+  assert_eq!(WithSkipped::A.try_into_word(), Some(0));
+  assert_eq!(WithSkipped::B(Three::C).try_into_word(), Some(3));
+  assert_eq!(WithSkipped::Sentinel.try_into_word(), None);
+  assert_eq!(WithSkipped::Other(7).try_into_word(), None);
+  assert_eq!(Three::B.try_into_word(), Some(1));
+}
+
+#[test]
+fn test_try_from_value() {
+  assert_eq!(
+    EnumIndex::try_from_value(WithSkipped::B(Three::A)),
+    EnumIndex::from_usize(1)
+  );
+  assert_eq!(EnumIndex::try_from_value(WithSkipped::Sentinel), None);
+}
+
+#[test]
+#[should_panic(expected = "Skipped values have no index.")]
+fn test_into_word_skipped() {
+  WithSkipped::Other(7).into_word();
+}
+
+#[test]
+fn test_map_try_get() {
+  let mut map = EnumMap::<WithSkipped, u8>::new();
+  *map.try_get_mut(WithSkipped::A).unwrap() = 5;
+  assert_eq!(map.try_get(WithSkipped::A), Some(&5));
+  assert_eq!(map.try_get(WithSkipped::Sentinel), None);
+  assert_eq!(map.try_get_mut(WithSkipped::Other(0)), None);
+}
+
+#[test]
+fn test_opt_map_try_insert() {
+  let mut map = EnumOptionMap::<WithSkipped, u8>::new();
+  assert_eq!(map.try_insert(WithSkipped::B(Three::B), 1), Ok(None));
+  assert_eq!(map.try_insert(WithSkipped::B(Three::B), 2), Ok(Some(1)));
+  assert_eq!(map.try_insert(WithSkipped::Sentinel, 3), Err(3));
+  assert_eq!(map.try_get(WithSkipped::B(Three::B)), Some(&2));
+  assert_eq!(map.try_get(WithSkipped::A), None);
+  assert_eq!(map.try_get(WithSkipped::Other(1)), None);
+  assert_eq!(map.count(), 1);
+}
+
+#[test]
+fn test_set_try_insert() {
+  let mut set = EnumSet::<WithSkipped>::new();
+  assert_eq!(set.try_insert(WithSkipped::B(Three::A)), Some(false));
+  assert_eq!(set.try_insert(WithSkipped::B(Three::A)), Some(true));
+  assert_eq!(set.try_insert(WithSkipped::Sentinel), None);
+  assert_eq!(set.try_contains(WithSkipped::B(Three::A)), Some(true));
+  assert_eq!(set.try_contains(WithSkipped::A), Some(false));
+  assert_eq!(set.try_contains(WithSkipped::Other(2)), None);
+  assert_eq!(set.try_remove(WithSkipped::Other(2)), None);
+  assert_eq!(set.try_remove(WithSkipped::B(Three::A)), Some(true));
+  assert_eq!(set.count(), 0);
+}
+
+#[test]
+fn test_vec_try_get() {
+  let mut vec = EnumVec::<WithSkipped, u8>::new();
+  vec.try_push(1).unwrap();
+  vec.try_push(2).unwrap();
+  *vec.try_get_mut(WithSkipped::B(Three::A)).unwrap() += 10;
+  assert_eq!(vec.try_get(WithSkipped::A), Some(&1));
+  assert_eq!(vec.try_get(WithSkipped::B(Three::A)), Some(&12));
+  assert_eq!(vec.try_get(WithSkipped::B(Three::C)), None);
+  assert_eq!(vec.try_get(WithSkipped::Sentinel), None);
+  assert_eq!(vec.try_get_mut(WithSkipped::Other(3)), None);
+}
+
+#[test]
+fn test_permutation_from_order_skipped() {
+  let order: EnumVec<WithSkipped, WithSkipped> = [
+    WithSkipped::A,
+    WithSkipped::Sentinel,
+    WithSkipped::B(Three::A),
+    WithSkipped::B(Three::B),
+  ]
+  .into_iter()
+  .collect();
+  assert!(order.is_full());
+  assert!(EnumPermutation::from_order(order).is_none());
+}
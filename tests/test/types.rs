@@ -1,5 +1,7 @@
 use enumoid::Enumoid;
+use enumoid::enumoid_from_file;
 use enumoid::generate_enumoid;
+use enumoid::{EnumIndex, EnumSize};
 use std::fmt::Debug;
 
 /// Exposes the canonical, in-order list of every value inhabiting a type. The
@@ -53,6 +55,167 @@ impl GoldenValues for StructThree {
   ];
 }
 
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+#[index_type(auto)]
+pub enum AutoThree {
+  A,
+  B,
+  C,
+}
+
+impl GoldenValues for AutoThree {
+  const VALUES: &'static [Self] = &[AutoThree::A, AutoThree::B, AutoThree::C];
+}
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+#[enumoid(discriminant)]
+#[repr(u8)]
+pub enum ReprThree {
+  A,
+  B = 1,
+  C,
+}
+
+impl GoldenValues for ReprThree {
+  const VALUES: &'static [Self] = &[ReprThree::A, ReprThree::B, ReprThree::C];
+}
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+#[enumoid(discriminant)]
+#[repr(u16)]
+pub enum ReprSparse {
+  A = 10,
+  B = 20,
+  C = 5,
+}
+
+impl GoldenValues for ReprSparse {
+  const VALUES: &'static [Self] =
+    &[ReprSparse::A, ReprSparse::B, ReprSparse::C];
+}
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+pub enum OrderedCompound {
+  #[enumoid(order = 2)]
+  A,
+  #[enumoid(order = 0)]
+  B(#[enumoid(reverse)] Three),
+  #[enumoid(order = 1)]
+  C(Three),
+}
+
+impl GoldenValues for OrderedCompound {
+  const VALUES: &'static [Self] = &[
+    OrderedCompound::B(Three::C),
+    OrderedCompound::B(Three::B),
+    OrderedCompound::B(Three::A),
+    OrderedCompound::C(Three::A),
+    OrderedCompound::C(Three::B),
+    OrderedCompound::C(Three::C),
+    OrderedCompound::A,
+  ];
+}
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+pub struct ReversedStruct(#[enumoid(reverse)] pub Three);
+
+impl GoldenValues for ReversedStruct {
+  const VALUES: &'static [Self] = &[
+    ReversedStruct(Three::C),
+    ReversedStruct(Three::B),
+    ReversedStruct(Three::A),
+  ];
+}
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+#[repr(u8)]
+pub enum ReprOrdered {
+  #[enumoid(order = 1)]
+  A,
+  #[enumoid(order = 2)]
+  B,
+  #[enumoid(order = 0)]
+  C,
+}
+
+impl GoldenValues for ReprOrdered {
+  const VALUES: &'static [Self] =
+    &[ReprOrdered::C, ReprOrdered::A, ReprOrdered::B];
+}
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+#[enumoid(partial)]
+pub enum WithSkipped {
+  A,
+  #[enumoid(skip)]
+  Sentinel,
+  B(Three),
+  #[enumoid(skip)]
+  Other(u32),
+}
+
+impl GoldenValues for WithSkipped {
+  const VALUES: &'static [Self] = &[
+    WithSkipped::A,
+    WithSkipped::B(Three::A),
+    WithSkipped::B(Three::B),
+    WithSkipped::B(Three::C),
+  ];
+}
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+#[enumoid(discriminant)]
+pub enum Opcode {
+  Nop = 0x90,
+  Jmp = 0xE9,
+  Int3 = 0xCC,
+}
+
+/// An enum with explicit discriminants and its own conversions, which the
+/// derive leaves alone without `#[enumoid(discriminant)]`.
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+#[repr(u8)]
+pub enum Flag {
+  Off = 0x10,
+  On = 0x20,
+}
+
+impl From<Flag> for u8 {
+  fn from(flag: Flag) -> u8 {
+    flag as u8 >> 4
+  }
+}
+
+impl Flag {
+  pub fn discriminant(self) -> &'static str {
+    match self {
+      Flag::Off => "off",
+      Flag::On => "on",
+    }
+  }
+}
+
+/// Re-exports the library under another name, as a prelude crate might.
+pub mod prelude {
+  pub use enumoid as renamed;
+}
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+#[enumoid(crate = crate::test::types::prelude::renamed)]
+pub enum RenamedCrate {
+  A,
+  B(Three),
+}
+
+impl GoldenValues for RenamedCrate {
+  const VALUES: &'static [Self] = &[
+    RenamedCrate::A,
+    RenamedCrate::B(Three::A),
+    RenamedCrate::B(Three::B),
+    RenamedCrate::B(Three::C),
+  ];
+}
+
 #[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
 pub enum CompoundSeven {
   X(Three),
@@ -202,3 +365,116 @@ generate_enumoid!(
   A,
   1..=300
 );
+
+generate_enumoid!(
+  #[derive(Copy, Clone, Debug, PartialEq)]
+  pub Channel,
+  Ch,
+  0..=20 step 10,
+  99,
+  1..4,
+  pad = 3,
+  suffix = Hz,
+  number,
+  repr
+);
+
+impl GoldenValues for Channel {
+  const VALUES: &'static [Self] = &[
+    Channel::Ch000Hz,
+    Channel::Ch010Hz,
+    Channel::Ch020Hz,
+    Channel::Ch099Hz,
+    Channel::Ch001Hz,
+    Channel::Ch002Hz,
+    Channel::Ch003Hz,
+  ];
+}
+
+enumoid_from_file!(
+  #[derive(Copy, Clone, Debug, PartialEq)]
+  pub Sensor,
+  "tests/data/sensors.txt"
+);
+
+impl GoldenValues for Sensor {
+  const VALUES: &'static [Self] =
+    &[Sensor::Intake, Sensor::Exhaust, Sensor::Coolant];
+}
+
+enumoid_from_file!(
+  #[derive(Copy, Clone, Debug, PartialEq)]
+  pub Sku,
+  "tests/data/skus.csv"
+);
+
+enumoid_from_file!(
+  #[derive(Copy, Clone, Debug, PartialEq)]
+  pub Colour,
+  "tests/data/colours.json"
+);
+
+enumoid_from_file!(
+  #[derive(Copy, Clone, Debug, PartialEq)]
+  pub Escaped,
+  "tests/data/escapes.json"
+);
+
+enumoid_from_file!(
+  #[derive(Copy, Clone, Debug, PartialEq)]
+  pub QuotedSku,
+  "tests/data/quoted.csv"
+);
+
+// The largest Enumoid with `u8` index words.
+generate_enumoid!(
+  #[derive(Copy, Clone, Debug, PartialEq)]
+  pub TwoFiftyFive,
+  V,
+  1..=255
+);
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+#[index_type(auto)]
+pub enum AutoThreeHundred {
+  Few(Three),
+  Many(ThreeHundred),
+}
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+pub enum Slot<T> {
+  Empty,
+  Filled(T),
+  Reserved,
+}
+
+impl GoldenValues for Slot<Three> {
+  const VALUES: &'static [Self] = &[
+    Slot::Empty,
+    Slot::Filled(Three::A),
+    Slot::Filled(Three::B),
+    Slot::Filled(Three::C),
+    Slot::Reserved,
+  ];
+}
+
+#[derive(Copy, Clone, Debug, Enumoid, PartialEq)]
+pub struct Wrapped<T>(#[enumoid(reverse)] pub T);
+
+impl GoldenValues for Wrapped<Slot<Three>> {
+  const VALUES: &'static [Self] = &[
+    Wrapped(Slot::Reserved),
+    Wrapped(Slot::Filled(Three::C)),
+    Wrapped(Slot::Filled(Three::B)),
+    Wrapped(Slot::Filled(Three::A)),
+    Wrapped(Slot::Empty),
+  ];
+}
+
+impl GoldenValues for EnumSize<StructOne> {
+  const VALUES: &'static [Self] = &[EnumSize::EMPTY, EnumSize::FULL];
+}
+
+impl GoldenValues for EnumIndex<StructOne> {
+  const VALUES: &'static [Self] = &[EnumIndex::FIRST];
+}
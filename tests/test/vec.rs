@@ -1,5 +1,5 @@
 use crate::test::drop_tracker::DropTracker;
-use crate::test::types::{Sixteen, Three};
+use crate::test::types::{Sixteen, Three, ThreeHundred};
 use enumoid::EnumOptionMap;
 use enumoid::EnumSize;
 use enumoid::EnumVec;
@@ -1118,3 +1118,13 @@ fn test_iter_mut_double_ended() {
   assert_eq!(vec[Three::A], 11, "Expected front mutation to persist");
   assert_eq!(vec[Three::C], 31, "Expected back mutation to persist");
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_new_boxed() {
+  let mut vec = EnumVec::<ThreeHundred, [u64; 32]>::new_boxed();
+  assert!(vec.is_empty());
+  assert_eq!(vec.try_push([3; 32]), Ok(()));
+  assert_eq!(vec.get(ThreeHundred::A1), Some(&[3; 32]));
+  assert_eq!(vec.get(ThreeHundred::A2), None);
+}